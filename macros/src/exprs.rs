@@ -4,8 +4,8 @@ use syn::{
     punctuated::Punctuated,
     spanned::Spanned,
     visit_mut::{self, VisitMut},
-    Block, Expr, ExprBlock, GenericArgument, Ident, Item, Path, PathSegment, Result, Stmt, Type,
-    TypePath,
+    Block, Error, Expr, ExprBlock, ExprRange, GenericArgument, Ident, Item, Path, PathSegment,
+    RangeLimits, Result, Stmt, Type, TypePath,
 };
 
 use self::{r#const::handle_const, r#fn::handle_fn};
@@ -53,6 +53,19 @@ impl VisitMut for ExprVisitor {
         };
 
         if let GenericArgument::Const(c) = i {
+            if let Some((start, end)) = range_bounds(c) {
+                match handle_range(start, end, &mut self.current, output) {
+                    Ok((start, end)) => {
+                        let mod_id = &self.mod_id;
+                        *i = GenericArgument::Type(
+                            syn::parse_quote!(::xparse::ops::InRange<#mod_id::#start, #mod_id::#end>),
+                        );
+                    }
+                    Err(e) => self.output = Err(e),
+                }
+                return;
+            }
+
             match handle_expr(c, &mut self.current, output) {
                 Ok(Some(ty_id)) => {
                     *i = GenericArgument::Type(Type::Path(TypePath {
@@ -117,3 +130,53 @@ fn handle_expr(
     *current += 1;
     Ok(Some(ty_id))
 }
+
+fn range_bounds(c: &Expr) -> Option<(Expr, Expr)> {
+    let Expr::Block(ExprBlock {
+        block: Block { stmts, .. },
+        ..
+    }) = c
+    else {
+        return None;
+    };
+    let [Stmt::Expr(
+        Expr::Range(ExprRange {
+            start: Some(start),
+            end: Some(end),
+            limits: RangeLimits::Closed(_),
+            ..
+        }),
+        None,
+    )] = stmts.as_slice()
+    else {
+        return None;
+    };
+    Some((*start.clone(), *end.clone()))
+}
+
+fn handle_range(
+    start: Expr,
+    end: Expr,
+    current: &mut usize,
+    output: &mut TokenStream,
+) -> Result<(Ident, Ident)> {
+    let mut bound = |e: Expr| {
+        let ty_id = Ident::new(
+            format!("Expr{current}__").as_str(),
+            e.span().resolved_at(Span::mixed_site()),
+        );
+        let Some(i) = handle_const(&e, &ty_id) else {
+            return Err(Error::new(
+                e.span(),
+                "range bounds must be char, byte or suffixed number literals",
+            ));
+        };
+        output.extend(quote! {
+            pub(super) struct #ty_id;
+            #i
+        });
+        *current += 1;
+        Ok(ty_id)
+    };
+    Ok((bound(start)?, bound(end)?))
+}
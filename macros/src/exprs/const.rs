@@ -1,10 +1,10 @@
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
-use syn::{Block, Expr, ExprBlock, ExprCast, ExprLit, Ident, Lit, Stmt};
+use syn::{Block, Expr, ExprArray, ExprBlock, ExprCast, ExprLit, Ident, Lit, Stmt};
 
 pub fn handle_const(expr: &Expr, ty_id: &Ident) -> Option<TokenStream> {
     if let Expr::Lit(ExprLit { lit, .. }) = expr {
-        return handle_lit(lit, ty_id);
+        return Some(const_impl(lit, &lit_type(lit)?, ty_id));
     }
 
     if let Expr::Block(ExprBlock {
@@ -15,21 +15,39 @@ pub fn handle_const(expr: &Expr, ty_id: &Ident) -> Option<TokenStream> {
         if let Some(Stmt::Expr(Expr::Cast(ExprCast { ty, .. }), ..)) = stmts.last() {
             return Some(const_impl(expr, ty, ty_id));
         }
+
+        if let [Stmt::Expr(Expr::Array(ExprArray { elems, .. }), None)] = stmts.as_slice() {
+            let mut ty = None;
+            for elem in elems {
+                let Expr::Lit(ExprLit { lit, .. }) = elem else {
+                    return None;
+                };
+                let elem_ty = lit_type(lit)?;
+                match &ty {
+                    None => ty = Some(elem_ty),
+                    Some(ty) if ty.to_string() == elem_ty.to_string() => {}
+                    Some(_) => return None,
+                }
+            }
+            let len = elems.len();
+            let ty = ty?;
+            return Some(const_impl(expr, &quote!([#ty; #len]), ty_id));
+        }
     }
 
     None
 }
 
-fn handle_lit(lit: &Lit, ty_id: &Ident) -> Option<TokenStream> {
+fn lit_type(lit: &Lit) -> Option<TokenStream> {
     Some(match lit {
-        Lit::Char(lit) => const_impl(lit, &quote!(char), ty_id),
-        Lit::Byte(lit) => const_impl(lit, &quote!(u8), ty_id),
-        Lit::Str(lit) => const_impl(lit, &quote!(&'static str), ty_id),
-        Lit::ByteStr(lit) => const_impl(lit, &quote!(&'static [u8]), ty_id),
+        Lit::Char(_) => quote!(char),
+        Lit::Byte(_) => quote!(u8),
+        Lit::Str(_) => quote!(&'static str),
+        Lit::ByteStr(_) => quote!(&'static [u8]),
         Lit::Int(lit) if !lit.suffix().is_empty() => {
-            const_impl(lit, &Ident::new(lit.suffix(), lit.span()), ty_id)
+            Ident::new(lit.suffix(), lit.span()).into_token_stream()
         }
-        Lit::Float(lit) => const_impl(lit, &Ident::new(lit.suffix(), lit.span()), ty_id),
+        Lit::Float(lit) => Ident::new(lit.suffix(), lit.span()).into_token_stream(),
         _ => return None,
     })
 }
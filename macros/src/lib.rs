@@ -13,6 +13,7 @@ The behaviour is expected to be like normal type aliases, but it fixes the compi
 This is used when:
 + It contains recursive parser rules.
 + It contains inline const arguments.
++ It contains inline range arguments such as `{ 'a'..='z' }`, which become `InRange` predicates.
 
 ## Usage
 ```ignore
@@ -27,6 +28,16 @@ When dealing with recursive parser rules, input/output types should be specified
 type MyRule = Some<Recursive<Combinators>>;
 ```
 
+Left-recursive rules should additionally be marked with `#[left_recursive]`, which wraps them in `LeftRec`:
+
+```ignore
+#[parser(Input, Output)]
+#[left_recursive]
+type Sum = Or<And<Sum, Plus, Term>, Term>;
+```
+
+Rules marked with `#[memo]` are wrapped in `Memo`, caching their results by position for packrat parsing. Their arg, if any, must be zero-sized.
+
  */
 #[proc_macro_attribute]
 pub fn parser(args: TokenStream, input: TokenStream) -> TokenStream {
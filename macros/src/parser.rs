@@ -135,6 +135,22 @@ pub fn handle(
         *ty
     };
 
+    if let Some(i) = attrs.iter().position(|attr| match &attr.meta {
+        Meta::Path(path) => path.is_ident("left_recursive"),
+        _ => false,
+    }) {
+        attrs.remove(i);
+        ty = syn::parse_quote!(::xparse::ops::LeftRec<#ty>);
+    }
+
+    if let Some(i) = attrs.iter().position(|attr| match &attr.meta {
+        Meta::Path(path) => path.is_ident("memo"),
+        _ => false,
+    }) {
+        attrs.remove(i);
+        ty = syn::parse_quote!(::xparse::ops::Memo<#ty>);
+    }
+
     let expr_defs = handle_exprs(&ident, &mut ty)?;
     let span = generics.span().resolved_at(Span::mixed_site());
     let struct_generics = generics.clone();
@@ -152,11 +168,18 @@ pub fn handle(
         let at = ident_to_type(arg.clone());
         generics.lt_token.get_or_insert_with(|| Token![<](span));
         generics.gt_token.get_or_insert_with(|| Token![>](span));
+        // Recursive rules have no where clause to carry the bounds of their body, while `LeftRec`
+        // and `Memo` key their tables by the arg type, which must therefore be `'static`.
+        let bounds = if input_args.rec.is_some() {
+            syn::parse_quote!('static)
+        } else {
+            Default::default()
+        };
         generics.params.push(GenericParam::Type(TypeParam {
             attrs: Default::default(),
             ident: arg,
             colon_token: Some(Token![:](span)),
-            bounds: Default::default(),
+            bounds,
             eq_token: Default::default(),
             default: Default::default(),
         }));
@@ -199,6 +222,9 @@ pub fn handle(
     let a = Ident::new("__Arg", Span::mixed_site());
 
     let f = quote! {
+        const CUT: bool = <#ty as ::xparse::parse::ParseImpl<#input, #arg>>::CUT;
+        const NEVER: bool = <#ty as ::xparse::parse::ParseImpl<#input, #arg>>::NEVER;
+
         #[inline(always)]
         fn parse<#s: ::xparse::Source<Item = #input>>(input: &mut #s, arg: &#arg) -> ::xparse::Result<Self::Output> {
             <#ty as ::xparse::parse::ParseImpl<#input, #arg>>::parse(input, arg)
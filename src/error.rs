@@ -1,5 +1,8 @@
-use alloc::boxed::Box;
-use core::fmt::{Debug, Display};
+use alloc::{borrow::Cow, boxed::Box, vec, vec::Vec};
+use core::{
+    fmt::{self, Display, Formatter},
+    ops::Range,
+};
 
 use thiserror_no_std::Error;
 
@@ -10,8 +13,8 @@ pub enum Error {
     #[error("mismatch")]
     Mismatch,
 
-    #[error("mismatching {0}")]
-    NamedMismatch(&'static str),
+    #[error("expecting {0}")]
+    NamedMismatch(Names),
 
     #[error(transparent)]
     Hard(HardError),
@@ -26,11 +29,94 @@ pub enum HardError {
     NamedIncomplete {
         position: usize,
         name: &'static str,
-        component_name: &'static str,
+        component_name: Names,
     },
 
+    #[error("unexpected input at {position}")]
+    Unexpected { position: usize },
+
+    #[error("unexpected input at {position}, expecting {component_name}")]
+    NamedUnexpected {
+        position: usize,
+        component_name: Names,
+    },
+
+    #[error("numeric overflow at {position}")]
+    Overflow { position: usize },
+
+    #[error("trailing separator at {position}")]
+    TrailingSeparator { position: usize },
+
     #[error(transparent)]
-    Other(#[from] Box<dyn DynError + Send>),
+    Other(#[from] Box<DynError>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Names(NamesRepr);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum NamesRepr {
+    One(&'static str),
+    // Boxed to keep `Error` small, as it is moved around by every parser.
+    #[allow(clippy::box_collection)]
+    Many(Box<Vec<&'static str>>),
+}
+
+impl Names {
+    pub fn iter(&self) -> impl Iterator<Item = &'static str> + '_ {
+        match &self.0 {
+            NamesRepr::One(name) => core::slice::from_ref(name),
+            NamesRepr::Many(names) => names.as_slice(),
+        }
+        .iter()
+        .copied()
+    }
+
+    pub fn merge(&mut self, other: Names) {
+        for name in other.iter() {
+            if self.iter().any(|x| x == name) {
+                continue;
+            }
+            match &mut self.0 {
+                NamesRepr::One(first) => self.0 = NamesRepr::Many(Box::new(vec![*first, name])),
+                NamesRepr::Many(names) => names.push(name),
+            }
+        }
+    }
+}
+
+impl From<&'static str> for Names {
+    #[inline(always)]
+    fn from(name: &'static str) -> Self {
+        Self(NamesRepr::One(name))
+    }
+}
+
+impl Display for Names {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut names = self.iter().peekable();
+        let mut first = true;
+        while let Some(name) = names.next() {
+            if !first {
+                f.write_str(if names.peek().is_some() { ", " } else { " or " })?;
+            }
+            first = false;
+            f.write_str(name)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct Recovered {
+    pub error: Error,
+    pub range: Range<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Warning {
+    pub message: Cow<'static, str>,
+    pub range: Range<usize>,
 }
 
 impl<T: Into<HardError>> From<T> for Error {
@@ -39,5 +125,126 @@ impl<T: Into<HardError>> From<T> for Error {
     }
 }
 
-pub trait DynError: Debug + Display {}
-impl<T: Debug + Display + ?Sized> DynError for T {}
+impl Error {
+    pub fn position(&self) -> Option<usize> {
+        match self {
+            Self::Hard(e) => e.position(),
+            _ => None,
+        }
+    }
+
+    pub fn downcast_ref<T: core::error::Error + 'static>(&self) -> Option<&T> {
+        match self {
+            Self::Hard(e) => e.downcast_ref(),
+            _ => None,
+        }
+    }
+}
+
+impl HardError {
+    pub fn position(&self) -> Option<usize> {
+        match self {
+            Self::Incomplete { position, .. }
+            | Self::NamedIncomplete { position, .. }
+            | Self::Unexpected { position }
+            | Self::NamedUnexpected { position, .. }
+            | Self::Overflow { position }
+            | Self::TrailingSeparator { position } => Some(*position),
+            Self::Other(_) => None,
+        }
+    }
+
+    pub fn downcast_ref<T: core::error::Error + 'static>(&self) -> Option<&T> {
+        match self {
+            Self::Other(e) => e.downcast_ref(),
+            _ => None,
+        }
+    }
+}
+
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Hard(e) => e.source(),
+            _ => None,
+        }
+    }
+}
+
+impl core::error::Error for HardError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Other(e) => e.source(),
+            _ => None,
+        }
+    }
+}
+
+/// A boxed error of any type, which can be downcast to the concrete type.
+pub type DynError = dyn core::error::Error + Send + Sync;
+
+#[cfg(test)]
+mod test {
+    use super::{DynError, Error, Names};
+    use alloc::{boxed::Box, string::String, string::ToString, vec};
+
+    #[test]
+    fn names_test() {
+        let mut names = Names::from("Object");
+        assert_eq!(names.to_string(), "Object");
+        names.merge("Array".into());
+        assert_eq!(names.to_string(), "Object or Array");
+        let mut other = Names::from("Array");
+        other.merge("String".into());
+        other.merge("Object".into());
+        other.merge("Number".into());
+        names.merge(other);
+        assert_eq!(
+            Error::NamedMismatch(names).to_string(),
+            "expecting Object, Array, String or Number"
+        );
+    }
+
+    fn utf8_error() -> Error {
+        let e = String::from_utf8(vec![0xff]).unwrap_err();
+        (Box::new(e) as Box<DynError>).into()
+    }
+
+    #[test]
+    fn downcast_test() {
+        let e = utf8_error();
+        assert!(e.downcast_ref::<alloc::string::FromUtf8Error>().is_some());
+        assert!(e.downcast_ref::<core::num::ParseIntError>().is_none());
+        assert!(Error::Mismatch
+            .downcast_ref::<alloc::string::FromUtf8Error>()
+            .is_none());
+
+        let Error::Hard(super::HardError::Other(e)) = e else {
+            unreachable!()
+        };
+        let e = e.downcast::<core::num::ParseIntError>().unwrap_err();
+        assert!(e.downcast::<alloc::string::FromUtf8Error>().is_ok());
+    }
+
+    #[test]
+    fn source_test() {
+        use core::error::Error as _;
+
+        #[derive(Debug, thiserror_no_std::Error)]
+        #[error("invalid number")]
+        struct InvalidNumber(core::num::ParseIntError);
+
+        impl core::error::Error for InvalidNumber {
+            fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+                Some(&self.0)
+            }
+        }
+
+        let inner = "x".parse::<u8>().unwrap_err();
+        let e: Error = (Box::new(InvalidNumber(inner)) as Box<DynError>).into();
+        assert_eq!(e.to_string(), "invalid number");
+        let source = e.source().unwrap();
+        assert!(source.downcast_ref::<core::num::ParseIntError>().is_some());
+        assert!(Error::Mismatch.source().is_none());
+    }
+}
@@ -2,17 +2,15 @@ use std::collections::HashMap;
 
 use crate::{
     ops::{
-        And, AnyOf, Define, Discard, Expected, Map, Not, Optional, Or, Punctuated, Repeat, Seq,
-        TryMap, A,
+        num::Float, AllowTrailing, And, AnyOf, Define, Discard, Expected, Map, Not, Or, Punctuated,
+        Repeat, Seq, SkipWhile, TryMap, A,
     },
     parser,
     source::from_slice,
     DynError, Parse, Result,
 };
 
-#[parser]
-type Digit = AnyOf<b"0123456789">;
-
+#[allow(dead_code)]
 #[derive(Debug, Clone)]
 enum Value {
     String(String),
@@ -39,31 +37,17 @@ type Comma = A<b','>;
 type Quote = A<b'"'>;
 #[parser]
 type SQuote = A<b'\''>;
-#[parser]
-type Point = A<b'.'>;
 
 #[parser]
-type Spaces = Discard<Repeat<AnyOf<b" \r\n\t">>>;
-
-#[parser]
-type Integer = TryMap<Repeat<Digit, 1>, StringMapper>;
+type Spaces = Discard<SkipWhile<AnyOf<b" \r\n\t">>>;
 
 #[parser]
 #[name]
-type Number = TryMap<
-    And<Integer, Optional<And<Discard<Point>, Integer>>>,
+type Number = Map<
+    Float<f64, true>,
     {
-        fn map(int: String, frac: Option<String>) -> Result<Value> {
-            let int = if let Some(frac) = frac {
-                format!("{int}.{frac}")
-            } else {
-                int
-            };
-
-            let result = int
-                .parse()
-                .map_err(|x| Box::new(x) as Box<dyn DynError + Send>)?;
-            Ok(Value::Number(result))
+        fn map(v: f64) -> Value {
+            Value::Number(v)
         }
     },
 >;
@@ -103,7 +87,7 @@ type PRawString = TryMap<
 type StringMapper = Define<
     {
         fn map(v: Vec<u8>) -> Result<String> {
-            Ok(String::from_utf8(v).map_err(|x| Box::new(x) as Box<dyn DynError + Send>)?)
+            Ok(String::from_utf8(v).map_err(|x| Box::new(x) as Box<DynError>)?)
         }
     },
 >;
@@ -135,8 +119,11 @@ type Object = Map<
                         },
                     >,
                     Comma,
+                    0,
+                    { usize::MAX },
+                    AllowTrailing,
+                    HashMap<String, Value>,
                 >,
-                Discard<Optional<Comma>>,
                 Spaces,
                 Discard<RBrace>,
             >,
@@ -144,8 +131,8 @@ type Object = Map<
         >,
     >,
     {
-        fn map(v: Vec<(String, Value)>, _: Vec<u8>) -> Value {
-            Value::Object(HashMap::from_iter(v))
+        fn map(v: HashMap<String, Value>, _: Vec<u8>) -> Value {
+            Value::Object(v)
         }
     },
 >;
@@ -156,7 +143,11 @@ type Array = Map<
     And<
         Discard<LBracket>,
         Expected<
-            And<Punctuated<PValue, Comma>, Discard<Optional<Comma>>, Spaces, Discard<RBracket>>,
+            And<
+                Punctuated<PValue, Comma, 0, { usize::MAX }, AllowTrailing>,
+                Spaces,
+                Discard<RBracket>,
+            >,
             "Array",
         >,
     >,
@@ -185,6 +176,16 @@ fn sync_test() {
     println!("{s:?}");
 }
 
+#[test]
+fn mismatch_test() {
+    let mut source = from_slice(b"nil");
+    let e = PValue::parse(&mut source).unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "expecting Object, Array, String, Number or Bool"
+    );
+}
+
 #[cfg(feature = "async")]
 #[tokio::test]
 async fn async_test() {
@@ -1,4 +1,4 @@
-#![cfg_attr(not(test), no_std)]
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
 extern crate alloc;
 
 pub mod parse;
@@ -9,6 +9,8 @@ pub use source::AsyncSource;
 pub use source::{Source, SourceBase};
 mod error;
 pub use error::*;
+#[cfg(feature = "lsp")]
+pub mod lsp;
 pub mod ops;
 mod tuple;
 #[cfg(feature = "macros")]
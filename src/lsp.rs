@@ -0,0 +1,203 @@
+use crate::{Error, Recovered, Warning};
+use alloc::{string::String, string::ToString, vec::Vec};
+use serde::{Serialize, Serializer};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Position {
+    pub line: u32,
+    pub character: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Range {
+    pub start: Position,
+    pub end: Position,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error = 1,
+    Warning = 2,
+    Information = 3,
+    Hint = 4,
+}
+
+impl Serialize for Severity {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(*self as u8)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Diagnostic {
+    pub range: Range,
+    pub severity: Severity,
+    pub message: String,
+}
+
+/// Maps positions reported by parsers onto lines and UTF-16 columns of the parsed text.
+pub struct LineIndex<'a> {
+    text: &'a str,
+    lines: Vec<Line>,
+    chars: bool,
+}
+
+#[derive(Clone, Copy)]
+struct Line {
+    byte: usize,
+    char: usize,
+}
+
+impl<'a> LineIndex<'a> {
+    /// For positions counted in bytes, as produced by parsers over `u8`.
+    pub fn new(text: &'a str) -> Self {
+        Self::build(text, false)
+    }
+
+    /// For positions counted in chars, as produced by parsers over `char`.
+    pub fn chars(text: &'a str) -> Self {
+        Self::build(text, true)
+    }
+
+    fn build(text: &'a str, chars: bool) -> Self {
+        let mut lines = Vec::from([Line { byte: 0, char: 0 }]);
+        for (char, (byte, c)) in text.char_indices().enumerate() {
+            if c == '\n' {
+                lines.push(Line {
+                    byte: byte + 1,
+                    char: char + 1,
+                });
+            }
+        }
+        Self { text, lines, chars }
+    }
+
+    pub fn position(&self, offset: usize) -> Position {
+        let line = self
+            .lines
+            .partition_point(|x| (if self.chars { x.char } else { x.byte }) <= offset)
+            - 1;
+        let start = self.lines[line];
+        let end = self.lines.get(line + 1).map_or(self.text.len(), |x| x.byte);
+        let text = &self.text[start.byte..end];
+        let character = if self.chars {
+            text.chars()
+                .take(offset - start.char)
+                .map(char::len_utf16)
+                .sum()
+        } else {
+            text.char_indices()
+                .take_while(|(i, _)| *i < offset - start.byte)
+                .map(|(_, c)| c.len_utf16())
+                .sum::<usize>()
+        };
+        Position {
+            line: line as u32,
+            character: character as u32,
+        }
+    }
+
+    pub fn range(&self, range: core::ops::Range<usize>) -> Range {
+        Range {
+            start: self.position(range.start),
+            end: self.position(range.end),
+        }
+    }
+
+    /// Converts an error that aborted parsing, located at `fallback` if it carries no position.
+    pub fn error(&self, error: &Error, fallback: usize) -> Diagnostic {
+        let position = error.position().unwrap_or(fallback);
+        Diagnostic {
+            range: self.range(position..position),
+            severity: Severity::Error,
+            message: error.to_string(),
+        }
+    }
+
+    pub fn recovered(&self, recovered: &Recovered) -> Diagnostic {
+        let start = recovered
+            .error
+            .position()
+            .filter(|x| recovered.range.contains(x))
+            .unwrap_or(recovered.range.start);
+        Diagnostic {
+            range: self.range(start..recovered.range.end),
+            severity: Severity::Error,
+            message: recovered.error.to_string(),
+        }
+    }
+
+    pub fn warning(&self, warning: &Warning) -> Diagnostic {
+        Diagnostic {
+            range: self.range(warning.range.clone()),
+            severity: Severity::Warning,
+            message: warning.message.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Diagnostic, LineIndex, Position, Range, Severity};
+    use crate::{Error, HardError, Warning};
+    use alloc::borrow::Cow;
+
+    const TEXT: &str = "ab\n\u{1F600}c\u{e9}d\nx";
+
+    #[test]
+    fn position_test() {
+        let bytes = LineIndex::new(TEXT);
+        let chars = LineIndex::chars(TEXT);
+        for (byte, char, line, character) in [
+            (0, 0, 0, 0),
+            (2, 2, 0, 2),
+            (3, 3, 1, 0),
+            (7, 4, 1, 2),
+            (8, 5, 1, 3),
+            (10, 6, 1, 4),
+            (13, 9, 2, 1),
+        ] {
+            let expected = Position { line, character };
+            assert_eq!(bytes.position(byte), expected);
+            assert_eq!(chars.position(char), expected);
+        }
+    }
+
+    #[test]
+    fn diagnostic_test() {
+        let index = LineIndex::new(TEXT);
+        let error = Error::Hard(HardError::Unexpected { position: 8 });
+        let at = |line, character| Position { line, character };
+        assert_eq!(
+            index.error(&error, 0),
+            Diagnostic {
+                range: Range {
+                    start: at(1, 3),
+                    end: at(1, 3),
+                },
+                severity: Severity::Error,
+                message: "unexpected input at 8".into(),
+            }
+        );
+
+        let warning = Warning {
+            message: Cow::Borrowed("deprecated"),
+            range: 1..3,
+        };
+        assert_eq!(
+            index.warning(&warning),
+            Diagnostic {
+                range: Range {
+                    start: at(0, 1),
+                    end: at(1, 0),
+                },
+                severity: Severity::Warning,
+                message: "deprecated".into(),
+            }
+        );
+        assert_eq!(
+            [Severity::Error, Severity::Warning].map(|x| x as u8),
+            [1, 2]
+        );
+    }
+}
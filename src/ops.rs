@@ -1,9 +1,16 @@
 use crate::{
     parse::{macros::impl_parse, ParseImpl},
-    Concat, Error, HardError, Result, SourceBase,
+    Concat, Error, HardError, Names, Recovered, Result, SourceBase, Warning,
 };
-use alloc::vec::Vec;
-use core::{borrow::Borrow, marker::PhantomData, ops::Range};
+use alloc::{borrow::Cow, vec::Vec};
+use core::{any::TypeId, borrow::Borrow, marker::PhantomData, ops::Range};
+
+pub mod binary;
+#[cfg(feature = "unicode")]
+mod case_fold;
+pub mod class;
+pub mod expr;
+pub mod num;
 
 pub trait Const {
     type Type;
@@ -19,6 +26,47 @@ pub trait Mapper<T, A> {
     fn map(v: T, a: &A) -> Self::Output;
 }
 
+pub trait Char: Copy + PartialEq {
+    fn to_ascii(self) -> Option<u8>;
+    fn units(s: &str) -> impl Iterator<Item = Self>;
+    fn encode(self, buf: &mut [u8; 4]) -> &[u8];
+}
+
+impl Char for u8 {
+    #[inline(always)]
+    fn to_ascii(self) -> Option<u8> {
+        self.is_ascii().then_some(self)
+    }
+
+    #[inline(always)]
+    fn units(s: &str) -> impl Iterator<Item = Self> {
+        s.bytes()
+    }
+
+    #[inline(always)]
+    fn encode(self, buf: &mut [u8; 4]) -> &[u8] {
+        buf[0] = self;
+        &buf[..1]
+    }
+}
+
+impl Char for char {
+    #[inline(always)]
+    fn to_ascii(self) -> Option<u8> {
+        self.is_ascii().then_some(self as u8)
+    }
+
+    #[inline(always)]
+    fn units(s: &str) -> impl Iterator<Item = Self> {
+        s.chars()
+    }
+
+    #[inline(always)]
+    fn encode(self, buf: &mut [u8; 4]) -> &[u8] {
+        self.encode_utf8(buf).as_bytes()
+    }
+}
+
 pub struct Define<T>(PhantomData<T>);
 
 impl<T, U: Mapper<T, A>, A> Mapper<T, A> for Define<U> {
@@ -43,6 +91,8 @@ impl<T: Const> Const for Define<T> {
 
 impl<I, U: ParseImpl<I, A>, A> ParseImpl<I, A> for Define<U> {
     type Output = U::Output;
+    const CUT: bool = U::CUT;
+    const NEVER: bool = U::NEVER;
 
     #[inline(always)]
     fn parse<S: crate::Source<Item = I>>(input: &mut S, arg: &A) -> Result<Self::Output> {
@@ -146,6 +196,27 @@ impl<C: Const<Type = G>, G: IntoIterator<Item = T>, T: Borrow<U>, U: PartialEq +
     ));
 }
 
+/// Matches items within the inclusive range `L..=H`, written as `{ L..=H }` inside `#[parser]`.
+pub struct InRange<L, H>(PhantomData<(L, H)>);
+
+impl<L: Const<Type = T>, H: Const<Type = T>, T: PartialOrd, A> Predicate<T, A> for InRange<L, H> {
+    #[inline(always)]
+    fn is(v: &T, _: &A) -> bool {
+        L::VALUE <= *v && *v <= H::VALUE
+    }
+}
+
+impl<L: Const<Type = T>, H: Const<Type = T>, T: PartialOrd + Clone, A> ParseImpl<T, A>
+    for InRange<L, H>
+{
+    type Output = <Is<Self> as ParseImpl<T, A>>::Output;
+    impl_parse!(parse, _await, |input: T, _arg: A| parse!(
+        Is::<Self>,
+        input,
+        &()
+    ));
+}
+
 pub struct Seq<C>(PhantomData<C>);
 
 impl<C: Const<Type = G>, G: IntoIterator<Item = T>, T: Borrow<I>, I: PartialEq + Clone, A>
@@ -169,6 +240,179 @@ impl<C: Const<Type = G>, G: IntoIterator<Item = T>, T: Borrow<I>, I: PartialEq +
     });
 }
 
+pub trait CaseFold<T> {
+    fn eq(a: &T, b: &T) -> bool;
+}
+
+/// Folds ASCII letters only.
+pub struct AsciiCase;
+
+/// Folds letters by Unicode simple case folding, which is ASCII folding for `u8`.
+#[cfg(feature = "unicode")]
+pub struct UnicodeCase;
+
+impl CaseFold<u8> for AsciiCase {
+    #[inline(always)]
+    fn eq(a: &u8, b: &u8) -> bool {
+        a.eq_ignore_ascii_case(b)
+    }
+}
+
+impl CaseFold<char> for AsciiCase {
+    #[inline(always)]
+    fn eq(a: &char, b: &char) -> bool {
+        a.eq_ignore_ascii_case(b)
+    }
+}
+
+#[cfg(feature = "unicode")]
+impl CaseFold<u8> for UnicodeCase {
+    #[inline(always)]
+    fn eq(a: &u8, b: &u8) -> bool {
+        a.eq_ignore_ascii_case(b)
+    }
+}
+
+#[cfg(feature = "unicode")]
+impl CaseFold<char> for UnicodeCase {
+    #[inline(always)]
+    fn eq(a: &char, b: &char) -> bool {
+        a == b || case_fold::fold(*a) == case_fold::fold(*b)
+    }
+}
+
+pub struct AnyOfNoCase<C, F = AsciiCase>(PhantomData<(C, F)>);
+
+impl<C: Const<Type = G>, G: IntoIterator<Item = T>, T: Borrow<U>, U, F: CaseFold<U>, A>
+    Predicate<U, A> for AnyOfNoCase<C, F>
+{
+    #[inline(always)]
+    fn is(v: &U, _: &A) -> bool {
+        C::VALUE.into_iter().any(|x| F::eq(v, x.borrow()))
+    }
+}
+
+impl<C: Const<Type = G>, G: IntoIterator<Item = T>, T: Borrow<U>, U: Clone, F: CaseFold<U>, A>
+    ParseImpl<U, A> for AnyOfNoCase<C, F>
+{
+    type Output = <Is<Self> as ParseImpl<U, A>>::Output;
+    impl_parse!(parse, _await, |input: U, _arg: A| parse!(
+        Is::<Self>,
+        input,
+        &()
+    ));
+}
+
+pub struct SeqNoCase<C, F = AsciiCase>(PhantomData<(C, F)>);
+
+impl<C: Const<Type = G>, G: IntoIterator<Item = T>, T: Borrow<I>, I: Clone, F: CaseFold<I>, A>
+    ParseImpl<I, A> for SeqNoCase<C, F>
+{
+    type Output = (Vec<I>,);
+    impl_parse!(parse, _await, |input: I, _arg: A| {
+        let i = C::VALUE.into_iter();
+        let mut count = 0;
+        for item in i {
+            match _await!(input.read(count + 1))?.get(count) {
+                Some(read) if F::eq(read, item.borrow()) => {
+                    count += 1;
+                }
+                _ => return Err(Error::Mismatch),
+            }
+        }
+        let result = _await!(input.read(count))?.to_vec();
+        input.consume(count);
+        Ok((result,))
+    });
+}
+
+/// Matches the keyword `C` as long as it is not followed by an item satisfying `IdentChar`.
+pub struct Keyword<C, IdentChar>(PhantomData<(C, IdentChar)>);
+
+impl<I: Char, C: Const<Type = &'static str>, P: Predicate<I, A>, A> ParseImpl<I, A>
+    for Keyword<C, P>
+{
+    type Output = (&'static str,);
+    impl_parse!(parse, _await, |input: I, arg: A| {
+        let mut count = 0;
+        for unit in I::units(C::VALUE) {
+            match _await!(input.read(count + 1))?.get(count) {
+                Some(read) if *read == unit => count += 1,
+                _ => return Err(Error::NamedMismatch(C::VALUE.into())),
+            }
+        }
+        if let Some(next) = _await!(input.read(count + 1))?.get(count) {
+            if P::is(next, arg) {
+                return Err(Error::NamedMismatch(C::VALUE.into()));
+            }
+        }
+        input.consume(count);
+        Ok((C::VALUE,))
+    });
+}
+
+/// Matches the longest of the strings in `C` in a single forward scan, outputting its index.
+pub struct OneOfSeq<C>(PhantomData<C>);
+
+const fn sorted_table<const N: usize>(words: [&'static str; N]) -> [(&'static str, usize); N] {
+    const fn less(a: &[u8], b: &[u8]) -> bool {
+        let mut i = 0;
+        while i < a.len() && i < b.len() {
+            if a[i] != b[i] {
+                return a[i] < b[i];
+            }
+            i += 1;
+        }
+        a.len() < b.len()
+    }
+
+    let mut table = [("", 0); N];
+    let mut i = 0;
+    while i < N {
+        table[i] = (words[i], i);
+        let mut j = i;
+        while j > 0 && less(table[j].0.as_bytes(), table[j - 1].0.as_bytes()) {
+            let t = table[j];
+            table[j] = table[j - 1];
+            table[j - 1] = t;
+            j -= 1;
+        }
+        i += 1;
+    }
+    table
+}
+
+impl<I: Char, C: Const<Type = [&'static str; N]>, A, const N: usize> ParseImpl<I, A>
+    for OneOfSeq<C>
+{
+    type Output = (usize,);
+    impl_parse!(parse, _await, |input: I, _arg: A| {
+        let table: &[(&str, usize)] = &const { sorted_table(C::VALUE) };
+        let (mut lo, mut hi) = (0, table.len());
+        let mut depth = 0;
+        let mut count = 0;
+        let mut best = None;
+        while lo < hi {
+            if table[lo].0.len() == depth {
+                best = Some((table[lo].1, count));
+            }
+            let Some(&item) = _await!(input.read(count + 1))?.get(count) else {
+                break;
+            };
+            for &unit in item.encode(&mut [0; 4]) {
+                let at = |e: &(&str, usize)| e.0.as_bytes().get(depth).copied();
+                lo += table[lo..hi].partition_point(|e| at(e) < Some(unit));
+                hi = lo + table[lo..hi].partition_point(|e| at(e) == Some(unit));
+                depth += 1;
+            }
+            count += 1;
+        }
+        let (index, count) = best.ok_or(Error::Mismatch)?;
+        input.consume(count);
+        Ok((index,))
+    });
+}
+
 pub struct Discard<T = NoOp>(PhantomData<T>);
 
 pub struct NoOp<T = ()>(PhantomData<T>);
@@ -205,6 +449,7 @@ impl<I, A, T> Predicate<I, A> for NoOp<T> {
 
 impl<I, O, T: ParseImpl<I, A, Output = O>, A> ParseImpl<I, A> for Never<T> {
     type Output = O;
+    const NEVER: bool = true;
     impl_parse!(parse, _await, |__: I, _arg: A| Err(Error::Mismatch));
 }
 
@@ -215,6 +460,27 @@ impl<I, A, T> Predicate<I, A> for Never<T> {
     }
 }
 
+/// Marks a cut point inside [`And`], after which mismatches become hard errors.
+pub struct Cut;
+
+impl<I, A> ParseImpl<I, A> for Cut {
+    type Output = ();
+    const CUT: bool = true;
+    impl_parse!(parse, _await, |__: I, _arg: A| Ok(()));
+}
+
+fn into_cut_error(e: Error, position: usize) -> Error {
+    match e {
+        Error::Mismatch => HardError::Unexpected { position }.into(),
+        Error::NamedMismatch(component_name) => HardError::NamedUnexpected {
+            position,
+            component_name,
+        }
+        .into(),
+        e => e,
+    }
+}
+
 #[allow(clippy::type_complexity)]
 pub struct And<
     T0 = NoOp,
@@ -392,16 +658,27 @@ impl<
     type Output = C31;
     impl_parse!(parse, _await, |input: I, arg: A| {
         let r = parse!(T0, input, arg)?;
+        let mut cut = T0::CUT;
+        macro_rules! parse_cut {
+            ($t:ty) => {
+                match parse!($t, input, arg) {
+                    Ok(item) => item,
+                    Err(e) if cut => return Err(into_cut_error(e, input.position())),
+                    Err(e) => return Err(e),
+                }
+            };
+        }
         macro_rules! impl_concat {
             ($r:ident, $($t:ty),*$(,)?) => {$(
-                let $r = $r.concat(parse!($t, input, arg)?);
+                let $r = $r.concat(parse_cut!($t));
+                cut |= <$t as ParseImpl<I, A>>::CUT;
             )*};
         }
         impl_concat!(
             r, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15, T16, T17, T18,
             T19, T20, T21, T22, T23, T24, T25, T26, T27, T28, T29, T30,
         );
-        let r = r.concat(parse!(T31, input, arg)?);
+        let r = r.concat(parse_cut!(T31));
         Ok(r)
     });
 }
@@ -491,6 +768,22 @@ impl<
     }
 }
 
+fn merge_names(names: &mut Option<Names>, other: Names) {
+    match names {
+        Some(names) => names.merge(other),
+        None => *names = Some(other),
+    }
+}
+
+/// Only names the mismatch if none of the failed alternatives was unnamed, as the names would be
+/// incomplete otherwise.
+fn names_or_mismatch(names: Option<Names>, unnamed: bool) -> Error {
+    match names {
+        Some(names) if !unnamed => Error::NamedMismatch(names),
+        _ => Error::Mismatch,
+    }
+}
+
 #[allow(clippy::type_complexity)]
 pub struct Or<
     T0 = Never<NoOp>,
@@ -636,6 +929,8 @@ impl<
 {
     type Output = O;
     impl_parse!(parse, _await, |input: I, arg: A| {
+        let mut names = None;
+        let mut unnamed = false;
         macro_rules! impl_or {
             ($i:expr, $($t:ty),*$(,)?) => {$(
                 let mut fork = $i.fork();
@@ -648,7 +943,14 @@ impl<
                         fork.join();
                         return Err(e);
                     }
-                    _ => drop(fork),
+                    Err(Error::NamedMismatch(n)) => {
+                        drop(fork);
+                        merge_names(&mut names, n);
+                    }
+                    _ => {
+                        drop(fork);
+                        unnamed |= !<$t as ParseImpl<I, A>>::NEVER;
+                    }
                 }
             )*};
         }
@@ -667,10 +969,17 @@ impl<
                 fork.join();
                 return Err(e);
             }
-            _ => drop(fork),
+            Err(Error::NamedMismatch(n)) => {
+                drop(fork);
+                merge_names(&mut names, n);
+            }
+            _ => {
+                drop(fork);
+                unnamed |= !T31::NEVER;
+            }
         }
 
-        Err(Error::Mismatch)
+        Err(names_or_mismatch(names, unnamed))
     });
 }
 
@@ -759,137 +1068,709 @@ impl<
     }
 }
 
-pub struct Repeat<T, const MIN: usize = 0, const MAX: usize = { usize::MAX }>(PhantomData<T>);
-
-impl<I, T: ParseImpl<I, A, Output = (O,)>, O, A, const MIN: usize, const MAX: usize> ParseImpl<I, A>
-    for Repeat<T, MIN, MAX>
-{
-    type Output = (Vec<O>,);
-    impl_parse!(parse, _await, |input: I, arg: A| {
-        let mut result = Vec::new();
-        let mut le = None;
-        for _ in 0..MAX {
-            match parse!(T, input, arg) {
-                Ok((item,)) => result.push(item),
-                Err(e @ Error::Hard(_)) => {
-                    return Err(e);
-                }
-                Err(e) => {
-                    le = Some(e);
-                    break;
-                }
-            }
-        }
-        if result.len() < MIN {
-            return Err(le.unwrap_or(Error::Mismatch));
-        }
-        Ok((result,))
-    });
-}
-
-pub struct Optional<T>(PhantomData<T>);
-
-impl<I, T: ParseImpl<I, A, Output = (O,)>, O, A> ParseImpl<I, A> for Optional<T> {
-    type Output = (Option<O>,);
-    impl_parse!(parse, _await, |input: I, arg: A| {
-        match parse!(T, input, arg) {
-            Ok((item,)) => Ok((Some(item),)),
-            Err(e @ Error::Hard(_)) => Err(e),
-            Err(_) => Ok((None,)),
-        }
-    });
+pub trait TieBreak {
+    const PREFER_LAST: bool;
 }
 
-pub struct Punctuated<T, P, const MIN: usize = 0, const MAX: usize = { usize::MAX }>(
-    PhantomData<(T, P)>,
-);
-
-impl<
-        I: core::fmt::Debug,
-        T: ParseImpl<I, A, Output = (O,)>,
-        O,
-        P: ParseImpl<I, A, Output = (PO,)>,
-        PO,
-        A,
-        const MIN: usize,
-        const MAX: usize,
-    > ParseImpl<I, A> for Punctuated<T, P, MIN, MAX>
-{
-    type Output = (Vec<O>, Vec<PO>);
-    impl_parse!(parse, _await, |input: I, arg: A| {
-        let mut result = Vec::new();
-        let mut puncts = Vec::new();
-        let mut le = None;
-
-        'matching: {
-            match parse!(T, input, arg) {
-                Ok((item,)) => result.push(item),
-                Err(e @ Error::Hard(_)) => {
-                    return Err(e);
-                }
-                Err(e) => {
-                    le = Some(e);
-                    break 'matching;
-                }
-            }
+pub struct PreferFirst;
 
-            for _ in 1..MAX {
-                let mut input = input.fork();
-                match parse!(P, &mut input, arg) {
-                    Ok((item,)) => puncts.push(item),
-                    Err(e @ Error::Hard(_)) => {
-                        return Err(e);
-                    }
-                    Err(e) => {
-                        le = Some(e);
-                        break;
-                    }
-                }
+pub struct PreferLast;
 
-                match parse!(T, &mut input, arg) {
-                    Ok((item,)) => {
-                        result.push(item);
-                        input.join();
-                    }
-                    Err(e @ Error::Hard(_)) => {
-                        return Err(e);
-                    }
-                    Err(e) => {
-                        puncts.pop();
-                        le = Some(e);
-                        break;
-                    }
-                }
-            }
-        }
-        if result.len() < MIN {
-            return Err(le.unwrap_or(Error::Mismatch));
-        }
-        Ok((result, puncts))
-    });
+impl TieBreak for PreferFirst {
+    const PREFER_LAST: bool = false;
 }
 
-pub struct Map<P, M = NoOp>(PhantomData<(P, M)>);
-
-pub struct TryMap<P, M>(PhantomData<(P, M)>);
-
-pub struct IsMap<P, M>(PhantomData<(P, M)>);
-
-impl<I, P: ParseImpl<I, A, Output = T>, M: Mapper<T, A, Output = U>, T, U, A> ParseImpl<I, A>
-    for Map<P, M>
-{
-    type Output = (U,);
-    impl_parse!(parse, _await, |input: I, arg: A| Ok((M::map(
-        parse!(P, input, arg)?,
-        arg
-    ),)));
+impl TieBreak for PreferLast {
+    const PREFER_LAST: bool = true;
 }
 
-impl<I, P: ParseImpl<I, A, Output = T>, M: Mapper<T, A, Output = Result<U>>, T, U, A>
-    ParseImpl<I, A> for TryMap<P, M>
-{
-    type Output = (U,);
-    impl_parse!(parse, _await, |input: I, arg: A| Ok((M::map(
+/// Tries every branch on a separate fork and commits the one that consumed the most input,
+/// breaking ties with `Tie`.
+#[allow(clippy::type_complexity)]
+pub struct LongestBy<
+    Tie,
+    T0 = Never<NoOp>,
+    T1 = Never<T0>,
+    T2 = Never<T0>,
+    T3 = Never<T0>,
+    T4 = Never<T0>,
+    T5 = Never<T0>,
+    T6 = Never<T0>,
+    T7 = Never<T0>,
+    T8 = Never<T0>,
+    T9 = Never<T0>,
+    T10 = Never<T0>,
+    T11 = Never<T0>,
+    T12 = Never<T0>,
+    T13 = Never<T0>,
+    T14 = Never<T0>,
+    T15 = Never<T0>,
+    T16 = Never<T0>,
+    T17 = Never<T0>,
+    T18 = Never<T0>,
+    T19 = Never<T0>,
+    T20 = Never<T0>,
+    T21 = Never<T0>,
+    T22 = Never<T0>,
+    T23 = Never<T0>,
+    T24 = Never<T0>,
+    T25 = Never<T0>,
+    T26 = Never<T0>,
+    T27 = Never<T0>,
+    T28 = Never<T0>,
+    T29 = Never<T0>,
+    T30 = Never<T0>,
+    T31 = Never<T0>,
+>(
+    PhantomData<(
+        Tie,
+        T0,
+        T1,
+        T2,
+        T3,
+        T4,
+        T5,
+        T6,
+        T7,
+        T8,
+        T9,
+        T10,
+        T11,
+        T12,
+        T13,
+        T14,
+        T15,
+        T16,
+        T17,
+        T18,
+        T19,
+        T20,
+        T21,
+        T22,
+        T23,
+        T24,
+        T25,
+        T26,
+        T27,
+        T28,
+        T29,
+        T30,
+        T31,
+    )>,
+);
+
+pub type Longest<
+    T0 = Never<NoOp>,
+    T1 = Never<T0>,
+    T2 = Never<T0>,
+    T3 = Never<T0>,
+    T4 = Never<T0>,
+    T5 = Never<T0>,
+    T6 = Never<T0>,
+    T7 = Never<T0>,
+    T8 = Never<T0>,
+    T9 = Never<T0>,
+    T10 = Never<T0>,
+    T11 = Never<T0>,
+    T12 = Never<T0>,
+    T13 = Never<T0>,
+    T14 = Never<T0>,
+    T15 = Never<T0>,
+    T16 = Never<T0>,
+    T17 = Never<T0>,
+    T18 = Never<T0>,
+    T19 = Never<T0>,
+    T20 = Never<T0>,
+    T21 = Never<T0>,
+    T22 = Never<T0>,
+    T23 = Never<T0>,
+    T24 = Never<T0>,
+    T25 = Never<T0>,
+    T26 = Never<T0>,
+    T27 = Never<T0>,
+    T28 = Never<T0>,
+    T29 = Never<T0>,
+    T30 = Never<T0>,
+    T31 = Never<T0>,
+> = LongestBy<
+    PreferFirst,
+    T0,
+    T1,
+    T2,
+    T3,
+    T4,
+    T5,
+    T6,
+    T7,
+    T8,
+    T9,
+    T10,
+    T11,
+    T12,
+    T13,
+    T14,
+    T15,
+    T16,
+    T17,
+    T18,
+    T19,
+    T20,
+    T21,
+    T22,
+    T23,
+    T24,
+    T25,
+    T26,
+    T27,
+    T28,
+    T29,
+    T30,
+    T31,
+>;
+
+impl<
+        Tie: TieBreak,
+        T0: ParseImpl<I, A, Output = O>,
+        T1: ParseImpl<I, A, Output = O>,
+        T2: ParseImpl<I, A, Output = O>,
+        T3: ParseImpl<I, A, Output = O>,
+        T4: ParseImpl<I, A, Output = O>,
+        T5: ParseImpl<I, A, Output = O>,
+        T6: ParseImpl<I, A, Output = O>,
+        T7: ParseImpl<I, A, Output = O>,
+        T8: ParseImpl<I, A, Output = O>,
+        T9: ParseImpl<I, A, Output = O>,
+        T10: ParseImpl<I, A, Output = O>,
+        T11: ParseImpl<I, A, Output = O>,
+        T12: ParseImpl<I, A, Output = O>,
+        T13: ParseImpl<I, A, Output = O>,
+        T14: ParseImpl<I, A, Output = O>,
+        T15: ParseImpl<I, A, Output = O>,
+        T16: ParseImpl<I, A, Output = O>,
+        T17: ParseImpl<I, A, Output = O>,
+        T18: ParseImpl<I, A, Output = O>,
+        T19: ParseImpl<I, A, Output = O>,
+        T20: ParseImpl<I, A, Output = O>,
+        T21: ParseImpl<I, A, Output = O>,
+        T22: ParseImpl<I, A, Output = O>,
+        T23: ParseImpl<I, A, Output = O>,
+        T24: ParseImpl<I, A, Output = O>,
+        T25: ParseImpl<I, A, Output = O>,
+        T26: ParseImpl<I, A, Output = O>,
+        T27: ParseImpl<I, A, Output = O>,
+        T28: ParseImpl<I, A, Output = O>,
+        T29: ParseImpl<I, A, Output = O>,
+        T30: ParseImpl<I, A, Output = O>,
+        T31: ParseImpl<I, A, Output = O>,
+        I,
+        O,
+        A,
+    > ParseImpl<I, A>
+    for LongestBy<
+        Tie,
+        T0,
+        T1,
+        T2,
+        T3,
+        T4,
+        T5,
+        T6,
+        T7,
+        T8,
+        T9,
+        T10,
+        T11,
+        T12,
+        T13,
+        T14,
+        T15,
+        T16,
+        T17,
+        T18,
+        T19,
+        T20,
+        T21,
+        T22,
+        T23,
+        T24,
+        T25,
+        T26,
+        T27,
+        T28,
+        T29,
+        T30,
+        T31,
+    >
+{
+    type Output = O;
+    impl_parse!(parse, _await, |input: I, arg: A| {
+        let start = input.position();
+        let mut names = None;
+        let mut unnamed = false;
+        let mut best = None;
+        macro_rules! impl_longest {
+            ($i:expr, $($t:ty),*$(,)?) => {$({
+                let mut fork = $i.fork();
+                match parse!($t, &mut fork, arg) {
+                    Ok(item) => {
+                        let len = fork.position() - start;
+                        if best.as_ref().map_or(true, |(_, l, _, _)| {
+                            len > *l || (Tie::PREFER_LAST && len == *l)
+                        }) {
+                            best = Some((item, len, fork.take_recovered(), fork.take_warnings()));
+                        }
+                    }
+                    Err(e @ Error::Hard(_)) => {
+                        fork.join();
+                        return Err(e);
+                    }
+                    Err(Error::NamedMismatch(n)) => {
+                        drop(fork);
+                        merge_names(&mut names, n);
+                    }
+                    _ => {
+                        drop(fork);
+                        unnamed |= !<$t as ParseImpl<I, A>>::NEVER;
+                    }
+                }
+            })*};
+        }
+        impl_longest!(
+            input, T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15, T16, T17,
+            T18, T19, T20, T21, T22, T23, T24, T25, T26, T27, T28, T29, T30, T31,
+        );
+
+        let Some((item, len, recovered, warnings)) = best else {
+            return Err(names_or_mismatch(names, unnamed));
+        };
+        input.consume(len);
+        recovered.into_iter().for_each(|r| input.report(r));
+        warnings.into_iter().for_each(|w| input.warn(w));
+        Ok(item)
+    });
+}
+
+/// The collection repeated items are collected into, which is either [`ToVec`] or any collection
+/// that is `Default + Extend`, such as `String` or `HashMap`.
+pub trait Collector<O> {
+    type Output: Default + Extend<O>;
+}
+
+pub struct ToVec;
+
+impl<O> Collector<O> for ToVec {
+    type Output = Vec<O>;
+}
+
+impl<O, C: Default + Extend<O>> Collector<O> for C {
+    type Output = C;
+}
+
+pub struct Repeat<T, const MIN: usize = 0, const MAX: usize = { usize::MAX }, C = ToVec>(
+    PhantomData<(T, C)>,
+);
+
+impl<
+        I,
+        T: ParseImpl<I, A, Output = (O,)>,
+        O,
+        A,
+        const MIN: usize,
+        const MAX: usize,
+        C: Collector<O>,
+    > ParseImpl<I, A> for Repeat<T, MIN, MAX, C>
+{
+    type Output = (C::Output,);
+    impl_parse!(parse, _await, |input: I, arg: A| {
+        let mut result = C::Output::default();
+        let mut count = 0;
+        let mut le = None;
+        for _ in 0..MAX {
+            match parse!(T, input, arg) {
+                Ok((item,)) => {
+                    result.extend(Some(item));
+                    count += 1;
+                }
+                Err(e @ Error::Hard(_)) => {
+                    return Err(e);
+                }
+                Err(e) => {
+                    le = Some(e);
+                    break;
+                }
+            }
+        }
+        if count < MIN {
+            return Err(le.unwrap_or(Error::Mismatch));
+        }
+        Ok((result,))
+    });
+}
+
+/// Like [`Repeat`], but folds the items with `F` into the accumulator parsed by `Init` instead of
+/// collecting them.
+pub struct Fold<T, Init, F, const MIN: usize = 0, const MAX: usize = { usize::MAX }>(
+    PhantomData<(T, Init, F)>,
+);
+
+impl<
+        I,
+        T: ParseImpl<I, A, Output = (O,)>,
+        Init: ParseImpl<I, A, Output = (Acc,)>,
+        F: Mapper<(Acc, O), A, Output = Acc>,
+        O,
+        Acc,
+        A,
+        const MIN: usize,
+        const MAX: usize,
+    > ParseImpl<I, A> for Fold<T, Init, F, MIN, MAX>
+{
+    type Output = (Acc,);
+    impl_parse!(parse, _await, |input: I, arg: A| {
+        let (mut acc,) = parse!(Init, input, arg)?;
+        let mut count = 0;
+        let mut le = None;
+        for _ in 0..MAX {
+            match parse!(T, input, arg) {
+                Ok((item,)) => {
+                    acc = F::map((acc, item), arg);
+                    count += 1;
+                }
+                Err(e @ Error::Hard(_)) => {
+                    return Err(e);
+                }
+                Err(e) => {
+                    le = Some(e);
+                    break;
+                }
+            }
+        }
+        if count < MIN {
+            return Err(le.unwrap_or(Error::Mismatch));
+        }
+        Ok((acc,))
+    });
+}
+
+/// Repeats `T` until `Term` matches, trying `Term` on a fork before each iteration.
+pub struct RepeatUntilKeep<T, Term, const MIN: usize = 0, const MAX: usize = { usize::MAX }>(
+    PhantomData<(T, Term)>,
+);
+
+pub type RepeatUntil<T, Term, const MIN: usize = 0, const MAX: usize = { usize::MAX }> =
+    RepeatUntilKeep<T, Discard<Term>, MIN, MAX>;
+
+impl<
+        I,
+        T: ParseImpl<I, A, Output = (O,)>,
+        O,
+        Term: ParseImpl<I, A>,
+        A,
+        const MIN: usize,
+        const MAX: usize,
+    > ParseImpl<I, A> for RepeatUntilKeep<T, Term, MIN, MAX>
+where
+    (Vec<O>,): Concat<Term::Output>,
+{
+    type Output = <(Vec<O>,) as Concat<Term::Output>>::Output;
+    impl_parse!(parse, _await, |input: I, arg: A| {
+        let mut result = Vec::new();
+        loop {
+            let mut names = None;
+            if result.len() >= MIN {
+                let mut input = input.fork();
+                match parse!(Term, &mut input, arg) {
+                    Ok(term) => {
+                        input.join();
+                        return Ok((result,).concat(term));
+                    }
+                    Err(e @ Error::Hard(_)) => {
+                        return Err(e);
+                    }
+                    Err(Error::NamedMismatch(n)) => names = Some(n),
+                    Err(_) => {}
+                }
+            }
+            if result.len() >= MAX {
+                return Err(names.map_or(Error::Mismatch, Error::NamedMismatch));
+            }
+            match parse!(T, input, arg) {
+                Ok((item,)) => result.push(item),
+                Err(e @ Error::Hard(_)) => {
+                    return Err(e);
+                }
+                Err(e) => {
+                    if let Error::NamedMismatch(n) = e {
+                        merge_names(&mut names, n);
+                    }
+                    return Err(names.map_or(Error::Mismatch, Error::NamedMismatch));
+                }
+            }
+        }
+    });
+}
+
+/// Matches a run of items satisfying `P`, reading progressively larger windows of the buffered
+/// items at once.
+pub struct TakeWhile<P, const MIN: usize = 0, const MAX: usize = { usize::MAX }>(PhantomData<P>);
+
+/// Like [`TakeWhile`], but only outputs the length of the run.
+pub struct SkipWhile<P, const MIN: usize = 0, const MAX: usize = { usize::MAX }>(PhantomData<P>);
+
+impl<I: Clone, P: Predicate<I, A>, A, const MIN: usize, const MAX: usize> ParseImpl<I, A>
+    for TakeWhile<P, MIN, MAX>
+{
+    type Output = (Vec<I>,);
+    impl_parse!(parse, _await, |input: I, arg: A| {
+        let (len,) = parse!(Peek<SkipWhile<P, MIN, MAX>>, input, arg)?;
+        let items = _await!(input.read(len))?.to_vec();
+        input.consume(len);
+        Ok((items,))
+    });
+}
+
+impl<I, P: Predicate<I, A>, A, const MIN: usize, const MAX: usize> ParseImpl<I, A>
+    for SkipWhile<P, MIN, MAX>
+{
+    type Output = (usize,);
+    impl_parse!(parse, _await, |input: I, arg: A| {
+        let mut len = 0usize;
+        let mut window = 16;
+        loop {
+            // Only ask for what is already buffered, or the one item that decides the run, so
+            // that a stream is not waited on for items past the end of the run.
+            let buffered = input.available();
+            let want = len
+                .saturating_add(window)
+                .min(if buffered > len { buffered } else { len + 1 })
+                .min(MAX);
+            let items = _await!(input.read(want))?;
+            let end = items.len();
+            len += items[len..].iter().take_while(|v| P::is(v, arg)).count();
+            if len < end || end < want || len >= MAX {
+                break;
+            }
+            window = window.saturating_mul(2);
+        }
+        if len < MIN {
+            return Err(Error::Mismatch);
+        }
+        input.consume(len);
+        Ok((len,))
+    });
+}
+
+/// Matches exactly `N` items with a single read.
+pub struct Take<const N: usize>;
+
+/// Like [`Take`], but takes the count from the arg.
+pub struct TakeArg;
+
+impl<I: Clone, A, const N: usize> ParseImpl<I, A> for Take<N> {
+    type Output = (Vec<I>,);
+    impl_parse!(parse, _await, |input: I, _arg: A| {
+        let items = _await!(input.read(N))?;
+        if items.len() < N {
+            return Err(Error::Mismatch);
+        }
+        let items = items.to_vec();
+        input.consume(N);
+        Ok((items,))
+    });
+}
+
+impl<I: Clone, A: Clone + TryInto<usize>> ParseImpl<I, A> for TakeArg {
+    type Output = (Vec<I>,);
+    impl_parse!(parse, _await, |input: I, arg: A| {
+        let len = arg.clone().try_into().map_err(|_| Error::Mismatch)?;
+        let items = _await!(input.read(len))?;
+        if items.len() < len {
+            return Err(Error::Mismatch);
+        }
+        let items = items.to_vec();
+        input.consume(len);
+        Ok((items,))
+    });
+}
+
+pub struct Optional<T>(PhantomData<T>);
+
+impl<I, T: ParseImpl<I, A, Output = (O,)>, O, A> ParseImpl<I, A> for Optional<T> {
+    type Output = (Option<O>,);
+    impl_parse!(parse, _await, |input: I, arg: A| {
+        match parse!(T, input, arg) {
+            Ok((item,)) => Ok((Some(item),)),
+            Err(e @ Error::Hard(_)) => Err(e),
+            Err(_) => Ok((None,)),
+        }
+    });
+}
+
+pub trait Trailing {
+    const ALLOW: bool;
+    const REQUIRE: bool;
+    const FORBID: bool;
+}
+
+/// Leaves a separator that is not followed by an item unconsumed.
+pub struct LeaveTrailing;
+
+/// Rejects a separator that is not followed by an item with a hard error.
+pub struct NoTrailing;
+
+/// Accepts an optional separator after the last item.
+pub struct AllowTrailing;
+
+/// Rejects a missing separator after the last item with a hard error.
+pub struct RequireTrailing;
+
+impl Trailing for LeaveTrailing {
+    const ALLOW: bool = false;
+    const REQUIRE: bool = false;
+    const FORBID: bool = false;
+}
+
+impl Trailing for NoTrailing {
+    const ALLOW: bool = false;
+    const REQUIRE: bool = false;
+    const FORBID: bool = true;
+}
+
+impl Trailing for AllowTrailing {
+    const ALLOW: bool = true;
+    const REQUIRE: bool = false;
+    const FORBID: bool = false;
+}
+
+impl Trailing for RequireTrailing {
+    const ALLOW: bool = true;
+    const REQUIRE: bool = true;
+    const FORBID: bool = false;
+}
+
+pub struct Punctuated<
+    T,
+    P,
+    const MIN: usize = 0,
+    const MAX: usize = { usize::MAX },
+    R = LeaveTrailing,
+    C = ToVec,
+>(PhantomData<(T, P, R, C)>);
+
+impl<
+        I: core::fmt::Debug,
+        T: ParseImpl<I, A, Output = (O,)>,
+        O,
+        P: ParseImpl<I, A, Output = (PO,)>,
+        PO,
+        A,
+        const MIN: usize,
+        const MAX: usize,
+        R: Trailing,
+        C: Collector<O>,
+    > ParseImpl<I, A> for Punctuated<T, P, MIN, MAX, R, C>
+{
+    type Output = (C::Output, Vec<PO>);
+    impl_parse!(parse, _await, |input: I, arg: A| {
+        let mut result = C::Output::default();
+        let mut count = 0;
+        let mut puncts = Vec::new();
+        let mut le = None;
+
+        'matching: {
+            match parse!(T, input, arg) {
+                Ok((item,)) => {
+                    result.extend(Some(item));
+                    count += 1;
+                }
+                Err(e @ Error::Hard(_)) => {
+                    return Err(e);
+                }
+                Err(e) => {
+                    le = Some(e);
+                    break 'matching;
+                }
+            }
+
+            loop {
+                let mut input = input.fork();
+                let position = input.position();
+                match parse!(P, &mut input, arg) {
+                    Ok((item,)) => puncts.push(item),
+                    Err(e @ Error::Hard(_)) => {
+                        return Err(e);
+                    }
+                    Err(e) if R::REQUIRE => {
+                        return Err(into_cut_error(e, input.position()));
+                    }
+                    Err(e) => {
+                        le = Some(e);
+                        break;
+                    }
+                }
+
+                if count >= MAX {
+                    if R::ALLOW {
+                        input.join();
+                    } else {
+                        puncts.pop();
+                    }
+                    break;
+                }
+
+                match parse!(T, &mut input, arg) {
+                    Ok((item,)) => {
+                        result.extend(Some(item));
+                        count += 1;
+                        input.join();
+                    }
+                    Err(e @ Error::Hard(_)) => {
+                        return Err(e);
+                    }
+                    Err(e) if R::ALLOW => {
+                        input.join();
+                        le = Some(e);
+                        break;
+                    }
+                    Err(_) if R::FORBID => {
+                        return Err(HardError::TrailingSeparator { position }.into());
+                    }
+                    Err(e) => {
+                        puncts.pop();
+                        le = Some(e);
+                        break;
+                    }
+                }
+            }
+        }
+        if count < MIN {
+            return Err(le.unwrap_or(Error::Mismatch));
+        }
+        Ok((result, puncts))
+    });
+}
+
+pub struct Map<P, M = NoOp>(PhantomData<(P, M)>);
+
+pub struct TryMap<P, M>(PhantomData<(P, M)>);
+
+pub struct IsMap<P, M>(PhantomData<(P, M)>);
+
+impl<I, P: ParseImpl<I, A, Output = T>, M: Mapper<T, A, Output = U>, T, U, A> ParseImpl<I, A>
+    for Map<P, M>
+{
+    type Output = (U,);
+    impl_parse!(parse, _await, |input: I, arg: A| Ok((M::map(
+        parse!(P, input, arg)?,
+        arg
+    ),)));
+}
+
+impl<I, P: ParseImpl<I, A, Output = T>, M: Mapper<T, A, Output = Result<U>>, T, U, A>
+    ParseImpl<I, A> for TryMap<P, M>
+{
+    type Output = (U,);
+    impl_parse!(parse, _await, |input: I, arg: A| Ok((M::map(
         parse!(P, input, arg)?,
         arg
     )?,)));
@@ -936,13 +1817,251 @@ impl<I, T: ParseImpl<I, A>, N: Const<Type = &'static str>, A> ParseImpl<I, A> fo
     impl_parse!(parse, _await, |input: I, arg: A| parse!(T, input, arg)
         .map_err(|e| {
             if let Error::Mismatch = e {
-                Error::NamedMismatch(N::VALUE)
+                Error::NamedMismatch(N::VALUE.into())
             } else {
                 e
             }
         }));
 }
 
+/// Parses the left-recursive rule `T` by growing a seed: the recursive occurrences of `T` at the
+/// same position first fail, then yield the previous result until it stops consuming more input.
+pub struct LeftRec<T>(PhantomData<T>);
+
+struct Seed<O> {
+    result: Option<(O, usize)>,
+    recovered: Vec<Recovered>,
+    warnings: Vec<Warning>,
+}
+
+impl<I, T: ParseImpl<I, A> + 'static, A: 'static> ParseImpl<I, A> for LeftRec<T>
+where
+    T::Output: Clone + 'static,
+{
+    type Output = T::Output;
+    impl_parse!(parse, _await, |input: I, arg: A| {
+        let key = TypeId::of::<(Self, A)>();
+        let start = input.position();
+        let end = input.end();
+        if let Some(seed) = input.memo().get_mut::<Seed<T::Output>>(key, start, end) {
+            let (item, len) = seed.result.clone().ok_or(Error::Mismatch)?;
+            input.consume(len);
+            return Ok(item);
+        }
+
+        input.memo().insert(
+            key,
+            start,
+            end,
+            Seed::<T::Output> {
+                result: None,
+                recovered: Vec::new(),
+                warnings: Vec::new(),
+            },
+        );
+        let mut error = Error::Mismatch;
+        loop {
+            let mut fork = input.fork();
+            let (item, mut recovered, mut warnings) = match parse!(T, &mut fork, arg) {
+                Ok(item) => (item, fork.take_recovered(), fork.take_warnings()),
+                Err(e @ Error::Hard(_)) => {
+                    fork.join();
+                    input.memo().remove::<Seed<T::Output>>(key, start, end);
+                    return Err(e);
+                }
+                Err(e) => {
+                    error = e;
+                    break;
+                }
+            };
+            let len = fork.position() - start;
+            drop(fork);
+            let Some(seed) = input.memo().get_mut::<Seed<T::Output>>(key, start, end) else {
+                return Err(Error::Mismatch);
+            };
+            if seed.result.as_ref().is_some_and(|(_, l)| len <= *l) {
+                break;
+            }
+            seed.result = Some((item, len));
+            seed.recovered.append(&mut recovered);
+            seed.warnings.append(&mut warnings);
+        }
+
+        let Some(seed) = input.memo().remove::<Seed<T::Output>>(key, start, end) else {
+            return Err(Error::Mismatch);
+        };
+        let Some((item, len)) = seed.result else {
+            return Err(error);
+        };
+        input.consume(len);
+        seed.recovered.into_iter().for_each(|r| input.report(r));
+        seed.warnings.into_iter().for_each(|w| input.warn(w));
+        Ok(item)
+    });
+}
+
+/// Caches the result of `T` by position and window end on the source, so that it is parsed at most
+/// once at each position. Results recording recovered errors are not cached. Args are told apart
+/// by their type only, so they must be zero-sized, which is checked at compile time. Within a cycle
+/// of [`LeftRec`], the results cached are those of the intermediate seeds, so `Memo` should wrap
+/// the left-recursive rule rather than a rule inside the cycle.
+pub struct Memo<T>(PhantomData<T>);
+
+type Memoized<O> = core::result::Result<(O, usize, Vec<Warning>), Option<Names>>;
+
+impl<I, T: ParseImpl<I, A> + 'static, A: 'static> ParseImpl<I, A> for Memo<T>
+where
+    T::Output: Clone + 'static,
+{
+    type Output = T::Output;
+    impl_parse!(parse, _await, |input: I, arg: A| {
+        const {
+            assert!(
+                core::mem::size_of::<A>() == 0,
+                "Memo requires a zero-sized arg"
+            )
+        };
+        let key = TypeId::of::<(Self, A)>();
+        let start = input.position();
+        let end = input.end();
+        if let Some(memoized) = input.memo().get_mut::<Memoized<T::Output>>(key, start, end) {
+            let (item, len, warnings) = memoized
+                .clone()
+                .map_err(|names| names.map_or(Error::Mismatch, Error::NamedMismatch))?;
+            input.consume(len);
+            warnings.into_iter().for_each(|w| input.warn(w));
+            return Ok(item);
+        }
+
+        let mut fork = input.fork();
+        let memoized = match parse!(T, &mut fork, arg) {
+            Ok(item) => {
+                let len = fork.position() - start;
+                let recovered = fork.take_recovered();
+                let warnings = fork.take_warnings();
+                fork.join();
+                if !recovered.is_empty() {
+                    recovered.into_iter().for_each(|r| input.report(r));
+                    warnings.into_iter().for_each(|w| input.warn(w));
+                    return Ok(item);
+                }
+                warnings.iter().for_each(|w| input.warn(w.clone()));
+                Ok((item, len, warnings))
+            }
+            Err(e @ Error::Hard(_)) => {
+                fork.join();
+                return Err(e);
+            }
+            Err(Error::NamedMismatch(names)) => {
+                drop(fork);
+                Err(Some(names))
+            }
+            Err(_) => {
+                drop(fork);
+                Err(None)
+            }
+        };
+        input.memo().insert(key, start, end, memoized.clone());
+        memoized
+            .map(|(item, _, _)| item)
+            .map_err(|names| names.map_or(Error::Mismatch, Error::NamedMismatch))
+    });
+}
+
+/// Recovers from hard errors of `T` by recording them onto the source, skipping the input until
+/// `Until` matches (without consuming it) and then parsing `Fallback` in place of `T`.
+/// At least one item is skipped if `T` consumed nothing, and the hard error is passed through if
+/// nothing is left to skip. Mismatches are passed through as is.
+pub struct Recover<T, Until, Fallback>(PhantomData<(T, Until, Fallback)>);
+
+impl<I, T: ParseImpl<I, A>, U: ParseImpl<I, A>, F: ParseImpl<I, A, Output = T::Output>, A>
+    ParseImpl<I, A> for Recover<T, U, F>
+{
+    type Output = T::Output;
+    impl_parse!(parse, _await, |input: I, arg: A| {
+        let start = input.position();
+        let mut fork = input.fork();
+        let error = match parse!(T, &mut fork, arg) {
+            Ok(item) => {
+                fork.join();
+                return Ok(item);
+            }
+            Err(e @ Error::Hard(_)) => {
+                fork.join();
+                e
+            }
+            Err(e) => return Err(e),
+        };
+
+        loop {
+            // Without any progress, `Until` matching in place would recover forever.
+            if input.position() != start {
+                match parse!(U, &mut input.fork(), arg) {
+                    Ok(_) => break,
+                    Err(e @ Error::Hard(_)) => return Err(e),
+                    Err(_) => {}
+                }
+            }
+            if _await!(input.read(1))?.is_empty() {
+                if input.position() == start {
+                    return Err(error);
+                }
+                break;
+            }
+            input.consume(1);
+        }
+
+        input.report(Recovered {
+            error,
+            range: start..input.position(),
+        });
+        parse!(F, input, arg)
+    });
+}
+
+/// Records a warning with the message `M` over the input consumed by `T`.
+pub struct Warn<T, M>(PhantomData<(T, M)>);
+
+impl<I, T: ParseImpl<I, A>, M: Const<Type = &'static str>, A> ParseImpl<I, A> for Warn<T, M> {
+    type Output = T::Output;
+    impl_parse!(parse, _await, |input: I, arg: A| {
+        let start = input.position();
+        let result = parse!(T, input, arg)?;
+        input.warn(Warning {
+            message: Cow::Borrowed(M::VALUE),
+            range: start..input.position(),
+        });
+        Ok(result)
+    });
+}
+
+/// Maps the output of `P` with a mapper returning the mapped value and an optional warning message.
+pub struct WarnMap<P, M>(PhantomData<(P, M)>);
+
+impl<
+        I,
+        P: ParseImpl<I, A, Output = T>,
+        M: Mapper<T, A, Output = (U, Option<W>)>,
+        T,
+        U,
+        W: Into<Cow<'static, str>>,
+        A,
+    > ParseImpl<I, A> for WarnMap<P, M>
+{
+    type Output = (U,);
+    impl_parse!(parse, _await, |input: I, arg: A| {
+        let start = input.position();
+        let (result, warning) = M::map(parse!(P, input, arg)?, arg);
+        if let Some(message) = warning {
+            input.warn(Warning {
+                message: message.into(),
+                range: start..input.position(),
+            });
+        }
+        Ok((result,))
+    });
+}
+
 pub struct Peek<T>(PhantomData<T>);
 
 impl<T: ParseImpl<I, A>, I, A> ParseImpl<I, A> for Peek<T> {
@@ -954,6 +2073,20 @@ impl<T: ParseImpl<I, A>, I, A> ParseImpl<I, A> for Peek<T> {
     ));
 }
 
+/// Succeeds without consuming if `T` mismatches, and mismatches if `T` succeeds.
+pub struct NotFollowedBy<T>(PhantomData<T>);
+
+impl<T: ParseImpl<I, A>, I, A> ParseImpl<I, A> for NotFollowedBy<T> {
+    type Output = ();
+    impl_parse!(parse, _await, |input: I, arg: A| {
+        match parse!(T, &mut input.fork(), arg) {
+            Ok(_) => Err(Error::Mismatch),
+            Err(e @ Error::Hard(_)) => Err(e),
+            Err(_) => Ok(()),
+        }
+    });
+}
+
 pub struct AndWithArg<T0 = NoOp, T1 = NoOp>(PhantomData<(T0, T1)>);
 
 impl<I, T0: ParseImpl<I, A0, Output = (A1,)>, T1: ParseImpl<I, A1>, A0, A1: Clone> ParseImpl<I, A0>
@@ -969,6 +2102,31 @@ where
     });
 }
 
+/// Runs `T` within a window as long as the arg, which `T` must consume entirely.
+pub struct Bounded<T>(PhantomData<T>);
+
+impl<I, T: ParseImpl<I, L>, L: Clone + TryInto<usize>> ParseImpl<I, L> for Bounded<T> {
+    type Output = T::Output;
+    impl_parse!(parse, _await, |input: I, arg: L| {
+        let len = arg.clone().try_into().map_err(|_| Error::Mismatch)?;
+        let end = input.position().saturating_add(len);
+        let mut input = input.fork_bounded(len);
+        let result = parse!(T, &mut input, arg)?;
+        if input.position() != end {
+            return Err(HardError::Unexpected {
+                position: input.position(),
+            }
+            .into());
+        }
+        input.join();
+        Ok(result)
+    });
+}
+
+/// Parses a length with `Len`, then runs `Body` within a window of that length, which `Body`
+/// must consume entirely. Outputs the length followed by the output of `Body`.
+pub type LengthPrefixed<Len, Body> = AndWithArg<Len, Bounded<Body>>;
+
 pub struct MapRange<T, M = ConcatArg>(PhantomData<(T, M)>);
 
 impl<I, A, T: ParseImpl<I, A>, M: Mapper<T::Output, Range<usize>>> ParseImpl<I, A>
@@ -1018,3 +2176,849 @@ impl<I, A> ParseImpl<I, A> for End {
         }
     });
 }
+
+#[cfg(test)]
+mod test {
+    use super::{
+        binary::U8, num::Dec, AllowTrailing, And, AndWithArg, AnyOf, AnyOfNoCase, Cut, Discard,
+        Expected, Fold, Is, Keyword, LeftRec, LengthPrefixed, Longest, LongestBy, Map, Memo, NoOp,
+        NoTrailing, Not, NotFollowedBy, OneOfSeq, Or, PreferLast, Punctuated, Recover, Repeat,
+        RepeatUntil, RepeatUntilKeep, RequireTrailing, Seq, SeqNoCase, SkipWhile, Take, TakeArg,
+        TakeWhile, Warn, WarnMap, A,
+    };
+    use crate::{parser, source::from_slice, Error, HardError, Parse, Source, SourceBase};
+    use alloc::{collections::BTreeMap, string::String};
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    #[parser]
+    type Digits = Repeat<AnyOf<b"0123456789">, 1>;
+
+    #[parser]
+    type LParen = A<b'('>;
+
+    #[parser]
+    type Item = And<
+        Discard<LParen>,
+        Expected<And<Digits, Discard<A<b')'>>>, "Item">,
+        Discard<Repeat<A<b' '>>>,
+    >;
+
+    #[parser]
+    type Items = Repeat<Recover<Item, LParen, NoOp<(Vec<u8>,)>>>;
+
+    #[parser]
+    type Skip = Recover<Expected<Digits, "Num">, A<b'x'>, NoOp<(Vec<u8>,)>>;
+
+    #[test]
+    fn recover_test() {
+        let mut source = from_slice(b"(1) (2 (3) (x) (45)");
+        let items = Items::parse(&mut source).unwrap();
+        assert_eq!(items, [&b"1"[..], b"", b"3", b"", b"45"]);
+        let recovered = source.take_recovered();
+        assert_eq!(recovered.len(), 2);
+        assert_eq!(recovered[0].range, 4..7);
+        assert_eq!(recovered[1].range, 11..15);
+        assert!(source.read(1).unwrap().is_empty());
+
+        let mut source = from_slice(b"xx");
+        assert!(Repeat::<Skip, 0, 1000>::parse(&mut source).is_err());
+        let recovered = source.take_recovered();
+        assert_eq!(recovered.len(), 2);
+        assert_eq!(recovered[0].range, 0..1);
+        assert_eq!(recovered[1].range, 1..2);
+    }
+
+    #[parser]
+    type Word = Or<
+        Map<
+            And<
+                Warn<Seq<{ b"colo" as &'static [u8] }>, "British spelling">,
+                Seq<{ b"ur" as &'static [u8] }>,
+            >,
+            {
+                fn map(a: Vec<u8>, b: Vec<u8>) -> usize {
+                    a.len() + b.len()
+                }
+            },
+        >,
+        WarnMap<
+            Repeat<AnyOf<b"abcdefghijklmnopqrstuvwxyz">, 1>,
+            {
+                fn map(v: Vec<u8>) -> (usize, Option<&'static str>) {
+                    (v.len(), (v.len() > 5).then_some("long word"))
+                }
+            },
+        >,
+    >;
+
+    #[parser]
+    type Words = Repeat<And<Word, Discard<Repeat<A<b' '>>>>>;
+
+    #[test]
+    fn warn_test() {
+        let mut source = from_slice(b"colour colors rainbow");
+        assert_eq!(Words::parse(&mut source).unwrap(), [6, 6, 7]);
+        let warnings = source.take_warnings();
+        assert_eq!(
+            warnings
+                .iter()
+                .map(|w| (&*w.message, w.range.clone()))
+                .collect::<Vec<_>>(),
+            [
+                ("British spelling", 0..4),
+                ("long word", 7..13),
+                ("long word", 14..21)
+            ]
+        );
+    }
+
+    #[parser]
+    #[name]
+    type RParen = A<b')'>;
+
+    #[parser]
+    type Group = Or<
+        Map<
+            And<Discard<LParen>, Cut, Digits, Discard<RParen>>,
+            {
+                fn map(v: Vec<u8>) -> Vec<u8> {
+                    v
+                }
+            },
+        >,
+        Seq<{ b"(x)" as &'static [u8] }>,
+    >;
+
+    #[test]
+    fn cut_test() {
+        assert_eq!(Group::parse(&mut from_slice(b"(12)")).unwrap(), b"12");
+        assert!(matches!(
+            Group::parse(&mut from_slice(b"(x)")),
+            Err(Error::Hard(HardError::Unexpected { position: 1 }))
+        ));
+        assert!(matches!(
+            Group::parse(&mut from_slice(b"(12")),
+            Err(Error::Hard(HardError::NamedUnexpected {
+                position: 3,
+                component_name
+            })) if component_name == "RParen".into()
+        ));
+    }
+
+    #[parser]
+    #[name]
+    type Comma = A<b','>;
+
+    #[parser]
+    type NoTrailingList = Map<
+        Punctuated<Digits, Comma, 0, { usize::MAX }, NoTrailing>,
+        {
+            fn map(v: Vec<Vec<u8>>, _: Vec<u8>) -> usize {
+                v.len()
+            }
+        },
+    >;
+
+    #[parser]
+    type LeaveTrailingList = Map<
+        Punctuated<Digits, Comma>,
+        {
+            fn map(v: Vec<Vec<u8>>, p: Vec<u8>) -> usize {
+                assert_eq!(v.len(), p.len() + 1);
+                v.len()
+            }
+        },
+    >;
+
+    #[parser]
+    type AllowTrailingList = Map<
+        Punctuated<Digits, Comma, 0, { usize::MAX }, AllowTrailing>,
+        {
+            fn map(v: Vec<Vec<u8>>, _: Vec<u8>) -> usize {
+                v.len()
+            }
+        },
+    >;
+
+    #[parser]
+    type RequireTrailingList = Map<
+        Punctuated<Digits, Comma, 0, { usize::MAX }, RequireTrailing>,
+        {
+            fn map(v: Vec<Vec<u8>>, _: Vec<u8>) -> usize {
+                v.len()
+            }
+        },
+    >;
+
+    #[test]
+    fn trailing_test() {
+        assert_eq!(NoTrailingList::parse(&mut from_slice(b"1,2")).unwrap(), 2);
+        assert!(matches!(
+            NoTrailingList::parse(&mut from_slice(b"1,2,")),
+            Err(Error::Hard(HardError::TrailingSeparator { position: 3 }))
+        ));
+
+        let mut source = from_slice(b"1,2,end");
+        assert_eq!(LeaveTrailingList::parse(&mut source).unwrap(), 2);
+        assert_eq!(source.read(5).unwrap(), b",end");
+
+        for input in [&b"1,2"[..], b"1,2,"] {
+            let mut source = from_slice(input);
+            assert_eq!(AllowTrailingList::parse(&mut source).unwrap(), 2);
+            assert!(source.read(1).unwrap().is_empty());
+        }
+
+        let mut source = from_slice(b"1,2,");
+        assert_eq!(RequireTrailingList::parse(&mut source).unwrap(), 2);
+        assert!(source.read(1).unwrap().is_empty());
+        assert!(matches!(
+            RequireTrailingList::parse(&mut from_slice(b"1,2")),
+            Err(Error::Hard(HardError::NamedUnexpected {
+                position: 3,
+                component_name
+            })) if component_name == "Comma".into()
+        ));
+        assert_eq!(RequireTrailingList::parse(&mut from_slice(b"")).unwrap(), 0);
+    }
+
+    #[parser]
+    #[name]
+    type CommentEnd = Seq<{ b"*/" as &'static [u8] }>;
+
+    #[parser]
+    type Comment = And<Discard<Seq<{ b"/*" as &'static [u8] }>>, RepeatUntil<Is<NoOp>, CommentEnd>>;
+
+    #[parser]
+    type Line = Map<
+        RepeatUntilKeep<Is<NoOp>, Or<A<b'\n'>, A<b';'>>, 1>,
+        {
+            fn map(v: Vec<u8>, end: u8) -> (Vec<u8>, u8) {
+                (v, end)
+            }
+        },
+    >;
+
+    #[test]
+    fn repeat_until_test() {
+        let mut source = from_slice(b"/* a * b */ rest");
+        assert_eq!(Comment::parse(&mut source).unwrap(), b" a * b ");
+        assert_eq!(source.read(5).unwrap(), b" rest");
+        assert!(matches!(
+            Comment::parse(&mut from_slice(b"/* a")),
+            Err(Error::NamedMismatch(names)) if names == "CommentEnd".into()
+        ));
+
+        let mut source = from_slice(b";a;");
+        assert_eq!(Line::parse(&mut source).unwrap(), (b";a".to_vec(), b';'));
+        assert!(source.read(1).unwrap().is_empty());
+    }
+
+    #[parser]
+    type Letters = TakeWhile<AnyOf<b"abcdefghijklmnopqrstuvwxyz">, 1>;
+
+    #[parser]
+    type Blanks = SkipWhile<A<b' '>, 0, 20>;
+
+    #[test]
+    fn take_while_test() {
+        let text = [&[b'a'; 40][..], b"  1"].concat();
+        let mut source = from_slice(&text);
+        assert_eq!(Letters::parse(&mut source).unwrap(), [b'a'; 40]);
+        assert_eq!(Blanks::parse(&mut source).unwrap(), 2);
+        assert!(Letters::parse(&mut source).is_err());
+        assert_eq!(source.read(2).unwrap(), b"1");
+
+        let mut source = from_slice(&[b' '; 30]);
+        assert_eq!(Blanks::parse(&mut source).unwrap(), 20);
+        assert_eq!(Blanks::parse(&mut source).unwrap(), 10);
+        assert_eq!(Blanks::parse(&mut source).unwrap(), 0);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn take_while_pending_test() {
+        use core::{
+            future::Future,
+            pin::{pin, Pin},
+            task::{Context, Poll, Waker},
+        };
+        use futures_core::Stream;
+
+        struct Pending(Vec<u8>);
+
+        impl Stream for Pending {
+            type Item = u8;
+            fn poll_next(mut self: Pin<&mut Self>, _: &mut Context) -> Poll<Option<u8>> {
+                if self.0.is_empty() {
+                    Poll::Pending
+                } else {
+                    Poll::Ready(Some(self.0.remove(0)))
+                }
+            }
+        }
+
+        let mut source = crate::source::form_stream(Pending(b"ab!".to_vec()));
+        let future = pin!(Letters::parse_async(&mut source));
+        assert!(matches!(
+            future.poll(&mut Context::from_waker(Waker::noop())),
+            Poll::Ready(Ok(v)) if v == b"ab"
+        ));
+    }
+
+    #[parser]
+    type Frame = Map<
+        AndWithArg<
+            Map<
+                Take<1>,
+                {
+                    fn map(v: Vec<u8>) -> u8 {
+                        v[0] - b'0'
+                    }
+                },
+            >,
+            TakeArg,
+        >,
+        {
+            fn map(len: u8, v: Vec<u8>) -> (u8, Vec<u8>) {
+                (len, v)
+            }
+        },
+    >;
+
+    #[test]
+    fn take_test() {
+        let mut source = from_slice(b"3abc2ef");
+        assert_eq!(Frame::parse(&mut source).unwrap(), (3, b"abc".to_vec()));
+        assert_eq!(Frame::parse(&mut source).unwrap(), (2, b"ef".to_vec()));
+        assert!(source.read(1).unwrap().is_empty());
+        assert!(Frame::parse(&mut from_slice(b"3ab")).is_err());
+    }
+
+    #[parser]
+    type Length = Map<
+        Digits,
+        {
+            fn map(v: Vec<u8>) -> usize {
+                v.iter().fold(0, |n, d| n * 10 + (d - b'0') as usize)
+            }
+        },
+    >;
+
+    #[parser]
+    type Netstring = Map<
+        LengthPrefixed<And<Length, Discard<A<b':'>>>, Repeat<Is<NoOp>>>,
+        {
+            fn map(_: usize, v: Vec<u8>) -> Vec<u8> {
+                v
+            }
+        },
+    >;
+
+    #[parser]
+    type Pair = Map<
+        LengthPrefixed<Length, And<Discard<A<b':'>>, Words>>,
+        {
+            fn map(len: usize, v: Vec<usize>) -> (usize, Vec<usize>) {
+                (len, v)
+            }
+        },
+    >;
+
+    #[parser(u8, usize)]
+    type Tlv = Or<
+        Map<
+            A<0u8>,
+            {
+                fn map(_: u8) -> usize {
+                    1
+                }
+            },
+        >,
+        Map<
+            And<Discard<A<1u8>>, LengthPrefixed<U8, Repeat<Tlv>>>,
+            {
+                fn map(_: u8, v: Vec<usize>) -> usize {
+                    v.iter().sum::<usize>() + 1
+                }
+            },
+        >,
+    >;
+
+    #[test]
+    fn length_prefixed_test() {
+        let mut source = from_slice(b"3:abc2:de");
+        assert_eq!(Netstring::parse(&mut source).unwrap(), b"abc");
+        assert_eq!(Netstring::parse(&mut source).unwrap(), b"de");
+        assert!(source.read(1).unwrap().is_empty());
+        assert!(matches!(
+            Netstring::parse(&mut from_slice(b"4:abc")),
+            Err(Error::Hard(_))
+        ));
+
+        let mut source = from_slice(b"6:ab cd, ef");
+        assert_eq!(Pair::parse(&mut source).unwrap(), (6, vec![2, 2]));
+        assert_eq!(source.read(4).unwrap(), b", ef");
+        assert!(matches!(
+            Pair::parse(&mut from_slice(b"4:ab,c")),
+            Err(Error::Hard(HardError::Unexpected { position: 4 }))
+        ));
+
+        let mut source = from_slice(&[1, 5, 0, 1, 2, 0, 0, 0]);
+        assert_eq!(Tlv::parse(&mut source).unwrap(), 5);
+        assert_eq!(source.read(2).unwrap(), &[0]);
+        assert!(Tlv::parse(&mut from_slice(&[1, 2, 1, 1, 0])).is_err());
+    }
+
+    #[parser]
+    type Select = SeqNoCase<{ b"select" as &'static [u8] }>;
+
+    #[parser]
+    type Hex = Repeat<AnyOfNoCase<b"0123456789abcdef">, 1>;
+
+    #[test]
+    fn no_case_test() {
+        assert_eq!(
+            Select::parse(&mut from_slice(b"SeLeCt *")).unwrap(),
+            b"SeLeCt"
+        );
+        assert!(Select::parse(&mut from_slice(b"selec")).is_err());
+
+        assert_eq!(
+            Hex::parse(&mut from_slice(b"dEaDbEeFg")).unwrap(),
+            b"dEaDbEeF"
+        );
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn unicode_case_test() {
+        use super::{CaseFold, UnicodeCase};
+
+        #[parser]
+        type Cafe = SeqNoCase<{ ['c', 'a', 'f', '\u{e9}'] as [char; 4] }, UnicodeCase>;
+
+        let chars = "CAF\u{c9}".chars().collect::<Vec<_>>();
+        assert_eq!(Cafe::parse(&mut from_slice(&chars)).unwrap(), chars);
+        let chars = "CAFE".chars().collect::<Vec<_>>();
+        assert!(Cafe::parse(&mut from_slice(&chars)).is_err());
+
+        for (a, b) in [
+            ('\u{17f}', 's'),
+            ('\u{17f}', 'S'),
+            ('\u{212a}', 'k'),
+            ('\u{3c2}', '\u{3c3}'),
+            ('\u{3c2}', '\u{3a3}'),
+            ('\u{1e9e}', '\u{df}'),
+        ] {
+            assert!(UnicodeCase::eq(&a, &b), "{a} {b}");
+        }
+        assert!(!UnicodeCase::eq(&'\u{130}', &'i'));
+        assert!(!UnicodeCase::eq(&'s', &'t'));
+    }
+
+    #[parser]
+    type Ident = Map<
+        And<
+            Or<{ b'a'..=b'z' }, { b'A'..=b'Z' }, A<b'_'>>,
+            TakeWhile<Or<{ b'a'..=b'z' }, { b'0'..=b'9' }, A<b'_'>>>,
+        >,
+        {
+            fn map(head: u8, tail: Vec<u8>) -> Vec<u8> {
+                [[head].as_slice(), &tail].concat()
+            }
+        },
+    >;
+
+    #[parser]
+    type NonDigits = Repeat<Not<{ '0'..='9' }>>;
+
+    #[test]
+    fn in_range_test() {
+        assert_eq!(
+            Ident::parse(&mut from_slice(b"Foo_9x+")).unwrap(),
+            b"Foo_9x"
+        );
+        assert!(Ident::parse(&mut from_slice(b"9x")).is_err());
+
+        let chars = "ab\u{e9}1".chars().collect::<Vec<_>>();
+        assert_eq!(
+            NonDigits::parse(&mut from_slice(&chars)).unwrap(),
+            chars[..3]
+        );
+    }
+
+    #[parser]
+    type If = And<Seq<{ b"if" as &'static [u8] }>, NotFollowedBy<{ b'a'..=b'z' }>>;
+
+    #[parser]
+    type CommentBody = Repeat<And<NotFollowedBy<CommentEnd>, Is<NoOp>>>;
+
+    #[test]
+    fn not_followed_by_test() {
+        let mut source = from_slice(b"if x");
+        assert_eq!(If::parse(&mut source).unwrap(), b"if");
+        assert_eq!(source.read(2).unwrap(), b" x");
+        assert!(If::parse(&mut from_slice(b"iffy")).is_err());
+
+        let mut source = from_slice(b"a*b*/");
+        assert_eq!(CommentBody::parse(&mut source).unwrap(), b"a*b");
+        assert_eq!(source.read(3).unwrap(), b"*/");
+    }
+
+    #[parser]
+    type True = Keyword<"true", Or<{ b'a'..=b'z' }, A<b'_'>>>;
+
+    #[parser]
+    type Fn = Keyword<"fn", Or<{ 'a'..='z' }, A<'_'>>>;
+
+    #[test]
+    fn keyword_test() {
+        let mut source = from_slice(b"true)");
+        assert_eq!(True::parse(&mut source).unwrap(), "true");
+        assert_eq!(source.read(1).unwrap(), b")");
+        assert!(True::parse(&mut from_slice(b"true")).is_ok());
+        for input in [&b"trueish"[..], b"tru", b"false"] {
+            assert!(matches!(
+                True::parse(&mut from_slice(input)),
+                Err(Error::NamedMismatch(names)) if names == "true".into()
+            ));
+        }
+
+        let chars = "fn f_n".chars().collect::<Vec<_>>();
+        let mut source = from_slice(&chars);
+        assert!(Fn::parse(&mut source).is_ok());
+        source.consume(1);
+        assert!(Fn::parse(&mut source).is_err());
+    }
+
+    #[parser]
+    type Op = OneOfSeq<{ ["<", "<=", "<<=", "=", "==", "<<"] }>;
+
+    #[parser]
+    type Accent = OneOfSeq<{ ["\u{e9}t\u{e9}", "\u{e9}", "et"] }>;
+
+    #[test]
+    fn one_of_seq_test() {
+        let mut source = from_slice(b"<<=<=<==x");
+        let mut ops = Vec::new();
+        while let Ok(op) = Op::parse(&mut source) {
+            ops.push(op);
+        }
+        assert_eq!(ops, [2, 1, 1, 3]);
+        assert_eq!(source.read(2).unwrap(), b"x");
+
+        let chars = "\u{e9}t\u{e9}\u{e9}tx".chars().collect::<Vec<_>>();
+        let mut source = from_slice(&chars);
+        assert_eq!(Accent::parse(&mut source).unwrap(), 0);
+        assert_eq!(Accent::parse(&mut source).unwrap(), 1);
+        assert!(Accent::parse(&mut from_slice(b"x")).is_err());
+        assert_eq!(Accent::parse(&mut from_slice("et".as_bytes())).unwrap(), 2);
+    }
+
+    #[parser]
+    type Lt = Map<
+        A<b'<'>,
+        {
+            fn map(_: u8) -> &'static str {
+                "<"
+            }
+        },
+    >;
+
+    #[parser]
+    type Le = Map<
+        Seq<{ b"<=" as &'static [u8] }>,
+        {
+            fn map(_: Vec<u8>) -> &'static str {
+                "<="
+            }
+        },
+    >;
+
+    #[parser]
+    type Less = Longest<
+        Lt,
+        Le,
+        Map<
+            Warn<Seq<{ b"=<" as &'static [u8] }>, "reversed">,
+            {
+                fn map(_: Vec<u8>) -> &'static str {
+                    "=<"
+                }
+            },
+        >,
+    >;
+
+    #[parser]
+    type LeOrLt = LongestBy<
+        PreferLast,
+        Le,
+        Map<
+            Seq<{ b"<=" as &'static [u8] }>,
+            {
+                fn map(_: Vec<u8>) -> &'static str {
+                    "le"
+                }
+            },
+        >,
+    >;
+
+    #[test]
+    fn longest_test() {
+        let mut source = from_slice(b"<=<=<");
+        assert_eq!(Less::parse(&mut source).unwrap(), "<=");
+        assert_eq!(source.read(1).unwrap(), b"<");
+        source.consume(1);
+        assert_eq!(Less::parse(&mut source).unwrap(), "=<");
+        assert_eq!(source.take_warnings()[0].range, 3..5);
+        assert!(source.read(1).unwrap().is_empty());
+        assert!(Less::parse(&mut from_slice(b">")).is_err());
+
+        assert_eq!(LeOrLt::parse(&mut from_slice(b"<=")).unwrap(), "le");
+    }
+
+    #[parser(u8, i64)]
+    #[left_recursive]
+    type Diff = Or<
+        Map<
+            And<Diff, Discard<A<b'-'>>, Dec<i64>>,
+            {
+                fn map(l: i64, r: i64) -> i64 {
+                    l - r
+                }
+            },
+        >,
+        Dec<i64>,
+    >;
+
+    #[parser(u8, usize)]
+    #[left_recursive]
+    type Bangs = Or<
+        Map<
+            And<BangsHead, Discard<A<b'!'>>>,
+            {
+                fn map(n: usize) -> usize {
+                    n + 1
+                }
+            },
+        >,
+        Map<
+            A<b'x'>,
+            {
+                fn map(_: u8) -> usize {
+                    0
+                }
+            },
+        >,
+    >;
+
+    #[parser(u8, usize)]
+    type BangsHead = And<Bangs>;
+
+    #[parser(u8, usize)]
+    #[left_recursive]
+    type Nested = Or<
+        Map<
+            And<Nested, Discard<A<b'!'>>>,
+            {
+                fn map(n: usize) -> usize {
+                    n + 1
+                }
+            },
+        >,
+        Map<
+            AndWithArg<NoOp<(usize,)>, Nested>,
+            {
+                fn map(_: usize, n: usize) -> usize {
+                    n + 10
+                }
+            },
+        >,
+        Map<
+            A<b'x'>,
+            {
+                fn map(_: u8) -> usize {
+                    0
+                }
+            },
+        >,
+    >;
+
+    #[test]
+    fn left_rec_test() {
+        let mut source = from_slice(b"10-4-3?");
+        assert_eq!(Diff::parse(&mut source).unwrap(), 3);
+        assert_eq!(source.read(2).unwrap(), b"?");
+        assert!(Diff::parse(&mut source).is_err());
+
+        let mut source = from_slice(b"x!!!?");
+        assert_eq!(Bangs::parse(&mut source).unwrap(), 3);
+        assert_eq!(source.read(2).unwrap(), b"?");
+        assert_eq!(LeftRec::<Bangs>::parse(&mut from_slice(b"x")).unwrap(), 0);
+
+        let mut source = from_slice(b"x!?");
+        assert_eq!(Nested::parse(&mut source).unwrap(), 11);
+        assert_eq!(source.read(2).unwrap(), b"?");
+    }
+
+    static COUNTED: AtomicUsize = AtomicUsize::new(0);
+
+    #[parser]
+    #[memo]
+    type Counted = Map<
+        Repeat<A<b'a'>, 1>,
+        {
+            fn map(v: Vec<u8>) -> usize {
+                COUNTED.fetch_add(1, Ordering::Relaxed);
+                v.len()
+            }
+        },
+    >;
+
+    #[parser]
+    type Choice = Or<And<Counted, Discard<A<b'x'>>>, And<Counted, Discard<A<b'y'>>>>;
+
+    #[parser]
+    #[memo]
+    type Lower = TakeWhile<AnyOf<b"abcd">>;
+
+    #[test]
+    fn memo_test() {
+        let mut source = from_slice(b"aaay");
+        assert_eq!(Choice::parse(&mut source).unwrap(), 3);
+        assert_eq!(COUNTED.load(Ordering::Relaxed), 1);
+        assert!(source.read(1).unwrap().is_empty());
+
+        assert!(Choice::parse(&mut from_slice(b"aaz")).is_err());
+        assert_eq!(COUNTED.load(Ordering::Relaxed), 2);
+        assert!(Memo::<Counted>::parse(&mut from_slice(b"b")).is_err());
+
+        let mut source = from_slice(b"abcd");
+        let mut fork = source.fork_bounded(2);
+        assert_eq!(Lower::parse(&mut fork).unwrap(), b"ab");
+        drop(fork);
+        assert_eq!(Lower::parse(&mut source).unwrap(), b"abcd");
+
+        let mut source = from_slice(b"aaayaay");
+        assert_eq!(Choice::parse(&mut source).unwrap(), 3);
+        assert_eq!(source.memo().len(), 0);
+        assert_eq!(Choice::parse(&mut source).unwrap(), 2);
+        assert_eq!(source.memo().len(), 0);
+    }
+
+    #[parser]
+    type Decimal = Fold<
+        Map<
+            AnyOf<b"0123456789">,
+            {
+                fn map(d: u8) -> u32 {
+                    (d - b'0') as u32
+                }
+            },
+        >,
+        NoOp<(u32,)>,
+        {
+            fn map(acc: u32, d: u32) -> u32 {
+                acc * 10 + d
+            }
+        },
+        1,
+        3,
+    >;
+
+    #[parser]
+    type Chain = Fold<
+        And<Discard<A<b'-'>>, Dec<i64>>,
+        Dec<i64>,
+        {
+            fn map(l: i64, r: i64) -> i64 {
+                l - r
+            }
+        },
+    >;
+
+    #[test]
+    fn fold_test() {
+        let mut source = from_slice(b"1234");
+        assert_eq!(Decimal::parse(&mut source).unwrap(), 123);
+        assert_eq!(source.read(2).unwrap(), b"4");
+        assert!(Decimal::parse(&mut from_slice(b"x")).is_err());
+
+        assert_eq!(Chain::parse(&mut from_slice(b"10-4-3")).unwrap(), 3);
+        assert_eq!(Chain::parse(&mut from_slice(b"7")).unwrap(), 7);
+        assert!(Chain::parse(&mut from_slice(b"-")).is_err());
+    }
+
+    #[parser]
+    type Text = Repeat<
+        Map<
+            AnyOf<b"abc">,
+            {
+                fn map(c: u8) -> char {
+                    c as char
+                }
+            },
+        >,
+        1,
+        { usize::MAX },
+        String,
+    >;
+
+    #[parser]
+    type Entries = Map<
+        Punctuated<
+            Map<
+                And<AnyOf<b"abc">, Discard<A<b'='>>, AnyOf<b"0123456789">>,
+                {
+                    fn map(k: u8, v: u8) -> (u8, u8) {
+                        (k, v - b'0')
+                    }
+                },
+            >,
+            Comma,
+            0,
+            { usize::MAX },
+            NoTrailing,
+            BTreeMap<u8, u8>,
+        >,
+        {
+            fn map(v: BTreeMap<u8, u8>, _: Vec<u8>) -> BTreeMap<u8, u8> {
+                v
+            }
+        },
+    >;
+
+    #[test]
+    fn collector_test() {
+        assert_eq!(Text::parse(&mut from_slice(b"cab!")).unwrap(), "cab");
+        assert!(Text::parse(&mut from_slice(b"!")).is_err());
+
+        assert_eq!(
+            Entries::parse(&mut from_slice(b"b=2,a=1,b=3")).unwrap(),
+            BTreeMap::from([(b'a', 1), (b'b', 3)])
+        );
+    }
+
+    #[parser]
+    #[name]
+    type NA = A<b'a'>;
+
+    #[parser]
+    #[name]
+    type NB = A<b'b'>;
+
+    #[parser]
+    type NAOrB = Or<NA, A<b'b'>>;
+
+    #[test]
+    fn or_names_test() {
+        assert!(matches!(
+            NAOrB::parse(&mut from_slice(b"c")),
+            Err(Error::Mismatch)
+        ));
+        assert!(matches!(
+            Or::<NA, NB>::parse(&mut from_slice(b"c")),
+            Err(Error::NamedMismatch(names)) if names.to_string() == "NA or NB"
+        ));
+    }
+}
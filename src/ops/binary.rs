@@ -0,0 +1,253 @@
+use crate::{
+    parse::{macros::impl_parse, ParseImpl},
+    Error, HardError, Result,
+};
+use core::marker::PhantomData;
+
+pub trait FromBytes: Sized {
+    const SIZE: usize;
+    fn from_be(bytes: &[u8]) -> Self;
+    fn from_le(bytes: &[u8]) -> Self;
+}
+
+macro_rules! impl_from_bytes {
+    ($($t:ty),*) => {
+        $(
+            impl FromBytes for $t {
+                const SIZE: usize = core::mem::size_of::<$t>();
+
+                #[inline(always)]
+                fn from_be(bytes: &[u8]) -> Self {
+                    Self::from_be_bytes(bytes.try_into().unwrap())
+                }
+
+                #[inline(always)]
+                fn from_le(bytes: &[u8]) -> Self {
+                    Self::from_le_bytes(bytes.try_into().unwrap())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_bytes!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+pub struct Be<T>(PhantomData<T>);
+
+pub struct Le<T>(PhantomData<T>);
+
+pub type U8 = Be<u8>;
+pub type I8 = Be<i8>;
+pub type BeU16 = Be<u16>;
+pub type BeU32 = Be<u32>;
+pub type BeU64 = Be<u64>;
+pub type BeU128 = Be<u128>;
+pub type BeI16 = Be<i16>;
+pub type BeI32 = Be<i32>;
+pub type BeI64 = Be<i64>;
+pub type BeI128 = Be<i128>;
+pub type BeF32 = Be<f32>;
+pub type BeF64 = Be<f64>;
+pub type LeU16 = Le<u16>;
+pub type LeU32 = Le<u32>;
+pub type LeU64 = Le<u64>;
+pub type LeU128 = Le<u128>;
+pub type LeI16 = Le<i16>;
+pub type LeI32 = Le<i32>;
+pub type LeI64 = Le<i64>;
+pub type LeI128 = Le<i128>;
+pub type LeF32 = Le<f32>;
+pub type LeF64 = Le<f64>;
+
+impl<T: FromBytes, A> ParseImpl<u8, A> for Be<T> {
+    type Output = (T,);
+    impl_parse!(parse, _await, |input: u8, _arg: A| {
+        let bytes = _await!(input.read(T::SIZE))?;
+        if bytes.len() < T::SIZE {
+            return Err(Error::Mismatch);
+        }
+        let value = T::from_be(bytes);
+        input.consume(T::SIZE);
+        Ok((value,))
+    });
+}
+
+impl<T: FromBytes, A> ParseImpl<u8, A> for Le<T> {
+    type Output = (T,);
+    impl_parse!(parse, _await, |input: u8, _arg: A| {
+        let bytes = _await!(input.read(T::SIZE))?;
+        if bytes.len() < T::SIZE {
+            return Err(Error::Mismatch);
+        }
+        let value = T::from_le(bytes);
+        input.consume(T::SIZE);
+        Ok((value,))
+    });
+}
+
+/// Decodes an unsigned LEB128 integer.
+pub struct Uleb128<T = u64>(PhantomData<T>);
+
+/// Decodes a signed LEB128 integer.
+pub struct Sleb128<T = i64>(PhantomData<T>);
+
+pub type Varint<T = u64> = Uleb128<T>;
+
+/// The longest encoding of a 128-bit integer, which may be padded up to this length.
+const LEB128_MAX_LEN: usize = u128::BITS.div_ceil(7) as usize;
+
+struct Leb128<const SIGNED: bool>;
+
+impl<A, const SIGNED: bool> ParseImpl<u8, A> for Leb128<SIGNED> {
+    type Output = (u128,);
+    impl_parse!(parse, _await, |input: u8, _arg: A| {
+        let position = input.position();
+        let mut value = 0u128;
+        let mut shift = 0u32;
+        let mut len = 0;
+        // Whether the bits beyond 128 are all zeros or all ones.
+        let (mut zeros, mut ones) = (true, true);
+        loop {
+            if len == LEB128_MAX_LEN {
+                return Err(HardError::Overflow { position }.into());
+            }
+            let bytes = _await!(input.read(len + 1))?;
+            let Some(&byte) = bytes.get(len) else {
+                return Err(Error::Mismatch);
+            };
+            len += 1;
+            let low = (byte & 0x7f) as u128;
+            let kept = u128::BITS.saturating_sub(shift).min(7);
+            if kept != 0 {
+                value |= low << shift;
+            }
+            if kept != 7 {
+                let high = low >> kept;
+                zeros &= high == 0;
+                ones &= high == 0x7f >> kept;
+            }
+            shift += 7;
+            if byte & 0x80 == 0 {
+                if SIGNED && shift < u128::BITS && byte & 0x40 != 0 {
+                    value |= !0 << shift;
+                }
+                let valid = if SIGNED && value >> (u128::BITS - 1) != 0 {
+                    ones
+                } else {
+                    zeros
+                };
+                if !valid {
+                    return Err(HardError::Overflow { position }.into());
+                }
+                input.consume(len);
+                return Ok((value,));
+            }
+        }
+    });
+}
+
+impl<T: TryFrom<u128>, A> ParseImpl<u8, A> for Uleb128<T> {
+    type Output = (T,);
+    impl_parse!(parse, _await, |input: u8, arg: A| {
+        let position = input.position();
+        let (value,) = parse!(Leb128<false>, input, arg)?;
+        Ok((value
+            .try_into()
+            .map_err(|_| HardError::Overflow { position })?,))
+    });
+}
+
+impl<T: TryFrom<i128>, A> ParseImpl<u8, A> for Sleb128<T> {
+    type Output = (T,);
+    impl_parse!(parse, _await, |input: u8, arg: A| {
+        let position = input.position();
+        let (value,) = parse!(Leb128<true>, input, arg)?;
+        Ok(((value as i128)
+            .try_into()
+            .map_err(|_| HardError::Overflow { position })?,))
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use super::{BeF32, BeU16, BeU32, LeF64, LeI16, LeU32, Sleb128, Uleb128, Varint, U8};
+    use crate::{source::from_slice, Error, HardError, Parse, Source};
+
+    #[test]
+    fn endian_test() {
+        let mut source = from_slice(&[0x12, 0x34, 0x56, 0x78, 0x9a]);
+        assert_eq!(BeU16::parse(&mut source).unwrap(), 0x1234);
+        assert_eq!(U8::parse(&mut source).unwrap(), 0x56);
+        assert!(BeU32::parse(&mut source).is_err());
+        assert_eq!(source.read(3).unwrap(), [0x78, 0x9a]);
+
+        let mut source = from_slice(&[0x78, 0x56, 0x34, 0x12, 0xfe, 0xff]);
+        assert_eq!(LeU32::parse(&mut source).unwrap(), 0x12345678);
+        assert_eq!(LeI16::parse(&mut source).unwrap(), -2);
+
+        let bytes = [1.5f32.to_be_bytes().as_slice(), &(-0.25f64).to_le_bytes()].concat();
+        let mut source = from_slice(&bytes);
+        assert_eq!(BeF32::parse(&mut source).unwrap(), 1.5);
+        assert_eq!(LeF64::parse(&mut source).unwrap(), -0.25);
+    }
+
+    #[test]
+    fn leb128_test() {
+        let mut source = from_slice(&[0xe5, 0x8e, 0x26, 0x01]);
+        assert_eq!(Varint::<u32>::parse(&mut source).unwrap(), 624485);
+        assert_eq!(Uleb128::<u8>::parse(&mut source).unwrap(), 1);
+
+        let mut source = from_slice(&[0xc0, 0xbb, 0x78, 0x7f, 0x3f]);
+        assert_eq!(Sleb128::<i64>::parse(&mut source).unwrap(), -123456);
+        assert_eq!(Sleb128::<i8>::parse(&mut source).unwrap(), -1);
+        assert_eq!(Sleb128::<i8>::parse(&mut source).unwrap(), 63);
+
+        assert!(matches!(
+            Uleb128::<u8>::parse(&mut from_slice(&[0x80, 0x02])),
+            Err(Error::Hard(HardError::Overflow { position: 0 }))
+        ));
+        assert!(matches!(
+            Uleb128::<u64>::parse(&mut from_slice(&[0x80])),
+            Err(Error::Mismatch)
+        ));
+
+        let mut padded = [0x80; 20];
+        padded[0] = 0x81;
+        padded[18] = 0x00;
+        assert_eq!(Uleb128::<u8>::parse(&mut from_slice(&padded)).unwrap(), 1);
+        assert!(matches!(
+            Uleb128::<u8>::parse(&mut from_slice(&padded[..18])),
+            Err(Error::Mismatch)
+        ));
+        padded[18] = 0x80;
+        assert!(matches!(
+            Uleb128::<u8>::parse(&mut from_slice(&padded)),
+            Err(Error::Hard(HardError::Overflow { position: 0 }))
+        ));
+        assert!(matches!(
+            Uleb128::<u128>::parse(&mut from_slice(&[0x80; 1000])),
+            Err(Error::Hard(HardError::Overflow { position: 0 }))
+        ));
+
+        let mut padded = [0xff; 19];
+        padded[18] = 0x7f;
+        assert_eq!(Sleb128::<i8>::parse(&mut from_slice(&padded)).unwrap(), -1);
+        padded[18] = 0x3f;
+        assert!(matches!(
+            Sleb128::<i8>::parse(&mut from_slice(&padded)),
+            Err(Error::Hard(HardError::Overflow { position: 0 }))
+        ));
+
+        let mut max = [0xff; 19];
+        max[18] = 0x03;
+        assert_eq!(
+            Uleb128::<u128>::parse(&mut from_slice(&max)).unwrap(),
+            u128::MAX
+        );
+        max[18] = 0x07;
+        assert!(matches!(
+            Uleb128::<u128>::parse(&mut from_slice(&max)),
+            Err(Error::Hard(HardError::Overflow { position: 0 }))
+        ));
+    }
+}
@@ -0,0 +1,198 @@
+use super::{Is, Predicate};
+use crate::{
+    parse::{macros::impl_parse, ParseImpl},
+    Result,
+};
+
+macro_rules! class {
+    ($($(#[$m:meta])* $name:ident = |$c:ident: char| $char:expr, |$b:ident: u8| $byte:expr;)*) => {
+        $(
+            $(#[$m])*
+            pub struct $name;
+
+            impl<A> Predicate<char, A> for $name {
+                #[inline(always)]
+                fn is(v: &char, _: &A) -> bool {
+                    let $c = *v;
+                    $char
+                }
+            }
+
+            impl<A> Predicate<u8, A> for $name {
+                #[inline(always)]
+                fn is(v: &u8, _: &A) -> bool {
+                    let $b = *v;
+                    $byte
+                }
+            }
+
+            impl<I: Clone, A> ParseImpl<I, A> for $name
+            where
+                Self: Predicate<I, A>,
+            {
+                type Output = (I,);
+                impl_parse!(parse, _await, |input: I, arg: A| parse!(Is::<Self>, input, arg));
+            }
+        )*
+    };
+}
+
+class! {
+    Alphabetic = |c: char| c.is_alphabetic(), |b: u8| b.is_ascii_alphabetic();
+    Numeric = |c: char| c.is_numeric(), |b: u8| b.is_ascii_digit();
+    Alphanumeric = |c: char| c.is_alphanumeric(), |b: u8| b.is_ascii_alphanumeric();
+    Whitespace = |c: char| c.is_whitespace(), |b: u8| b.is_ascii_whitespace();
+    Lowercase = |c: char| c.is_lowercase(), |b: u8| b.is_ascii_lowercase();
+    Uppercase = |c: char| c.is_uppercase(), |b: u8| b.is_ascii_uppercase();
+    Control = |c: char| c.is_control(), |b: u8| b.is_ascii_control();
+
+    AsciiAlphabetic = |c: char| c.is_ascii_alphabetic(), |b: u8| b.is_ascii_alphabetic();
+    AsciiDigit = |c: char| c.is_ascii_digit(), |b: u8| b.is_ascii_digit();
+    AsciiHexDigit = |c: char| c.is_ascii_hexdigit(), |b: u8| b.is_ascii_hexdigit();
+    AsciiAlphanumeric = |c: char| c.is_ascii_alphanumeric(), |b: u8| b.is_ascii_alphanumeric();
+    AsciiWhitespace = |c: char| c.is_ascii_whitespace(), |b: u8| b.is_ascii_whitespace();
+    AsciiPunctuation = |c: char| c.is_ascii_punctuation(), |b: u8| b.is_ascii_punctuation();
+    AsciiGraphic = |c: char| c.is_ascii_graphic(), |b: u8| b.is_ascii_graphic();
+    AsciiLowercase = |c: char| c.is_ascii_lowercase(), |b: u8| b.is_ascii_lowercase();
+    AsciiUppercase = |c: char| c.is_ascii_uppercase(), |b: u8| b.is_ascii_uppercase();
+    AsciiControl = |c: char| c.is_ascii_control(), |b: u8| b.is_ascii_control();
+}
+
+#[cfg(feature = "unicode")]
+class! {
+    /// Characters that may start an identifier, per Unicode Standard Annex #31.
+    XidStart = |c: char| unicode_ident::is_xid_start(c), |b: u8| b.is_ascii_alphabetic();
+    /// Characters that may continue an identifier, per Unicode Standard Annex #31.
+    XidContinue = |c: char| unicode_ident::is_xid_continue(c),
+        |b: u8| b.is_ascii_alphanumeric() || b == b'_';
+}
+
+/// Unicode general categories, with bytes classified as the ASCII characters they encode.
+#[cfg(feature = "unicode")]
+pub mod category {
+    use super::{Is, Predicate};
+    use crate::{
+        parse::{macros::impl_parse, ParseImpl},
+        Result,
+    };
+    use unicode_general_category::{get_general_category, GeneralCategory::*};
+
+    macro_rules! category {
+        ($($name:ident = $($category:ident)|+;)*) => {
+            class! {
+                $(
+                    $name = |c: char| matches!(get_general_category(c), $($category)|+),
+                        |b: u8| b.is_ascii() && matches!(get_general_category(b as char), $($category)|+);
+                )*
+            }
+        };
+    }
+
+    category! {
+        Letter = UppercaseLetter | LowercaseLetter | TitlecaseLetter | ModifierLetter | OtherLetter;
+        CasedLetter = UppercaseLetter | LowercaseLetter | TitlecaseLetter;
+        Lu = UppercaseLetter;
+        Ll = LowercaseLetter;
+        Lt = TitlecaseLetter;
+        Lm = ModifierLetter;
+        Lo = OtherLetter;
+        Mark = NonspacingMark | SpacingMark | EnclosingMark;
+        Mn = NonspacingMark;
+        Mc = SpacingMark;
+        Me = EnclosingMark;
+        Number = DecimalNumber | LetterNumber | OtherNumber;
+        Nd = DecimalNumber;
+        Nl = LetterNumber;
+        No = OtherNumber;
+        Punctuation = ConnectorPunctuation | DashPunctuation | OpenPunctuation | ClosePunctuation
+            | InitialPunctuation | FinalPunctuation | OtherPunctuation;
+        Pc = ConnectorPunctuation;
+        Pd = DashPunctuation;
+        Ps = OpenPunctuation;
+        Pe = ClosePunctuation;
+        Pi = InitialPunctuation;
+        Pf = FinalPunctuation;
+        Po = OtherPunctuation;
+        Symbol = MathSymbol | CurrencySymbol | ModifierSymbol | OtherSymbol;
+        Sm = MathSymbol;
+        Sc = CurrencySymbol;
+        Sk = ModifierSymbol;
+        So = OtherSymbol;
+        Separator = SpaceSeparator | LineSeparator | ParagraphSeparator;
+        Zs = SpaceSeparator;
+        Zl = LineSeparator;
+        Zp = ParagraphSeparator;
+        Other = Control | Format | Surrogate | PrivateUse | Unassigned;
+        Cc = Control;
+        Cf = Format;
+        Cs = Surrogate;
+        Co = PrivateUse;
+        Cn = Unassigned;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Alphabetic, AsciiHexDigit, Numeric, Whitespace};
+    use crate::{
+        ops::{And, Not, Repeat, TakeWhile},
+        parser,
+        source::from_slice,
+        Parse,
+    };
+    use alloc::vec::Vec;
+
+    #[parser]
+    type Word = TakeWhile<Alphabetic, 1>;
+
+    #[parser]
+    type NonSpace = Repeat<Not<Whitespace>, 1>;
+
+    #[test]
+    fn class_test() {
+        let chars = "\u{e9}t\u{e9}2".chars().collect::<Vec<_>>();
+        assert_eq!(Word::parse(&mut from_slice(&chars)).unwrap(), chars[..3]);
+        assert_eq!(Word::parse(&mut from_slice(b"ab\xe9")).unwrap(), b"ab");
+
+        let chars = "\u{2167}x\u{3000}".chars().collect::<Vec<_>>();
+        assert_eq!(
+            NonSpace::parse(&mut from_slice(&chars)).unwrap(),
+            chars[..2]
+        );
+        assert!(TakeWhile::<Numeric, 1>::parse(&mut from_slice(&chars)).is_ok());
+        assert!(And::<AsciiHexDigit>::parse(&mut from_slice(&chars)).is_err());
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn unicode_test() {
+        use super::{
+            category::{Lu, Sc},
+            XidContinue, XidStart,
+        };
+        use crate::ops::Map;
+
+        #[parser]
+        type Ident = Map<
+            And<XidStart, TakeWhile<XidContinue>>,
+            {
+                fn map(head: char, tail: Vec<char>) -> (char, usize) {
+                    (head, tail.len())
+                }
+            },
+        >;
+
+        let chars = "\u{3b1}_1 x".chars().collect::<Vec<_>>();
+        assert_eq!(
+            Ident::parse(&mut from_slice(&chars)).unwrap(),
+            ('\u{3b1}', 2)
+        );
+        assert!(Ident::parse(&mut from_slice(&['_'])).is_err());
+
+        let chars = "\u{20ac}\u{394}".chars().collect::<Vec<_>>();
+        let mut source = from_slice(&chars);
+        assert_eq!(Sc::parse(&mut source).unwrap(), '\u{20ac}');
+        assert_eq!(Lu::parse(&mut source).unwrap(), '\u{394}');
+        assert!(Lu::parse(&mut from_slice(b"a")).is_err());
+    }
+}
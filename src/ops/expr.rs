@@ -0,0 +1,270 @@
+use crate::{
+    ops::Mapper,
+    parse::{macros::impl_parse, ParseImpl},
+    Error, Result, SourceBase,
+};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+pub trait Assoc {
+    const RIGHT: bool;
+}
+
+pub struct Left;
+
+pub struct Right;
+
+impl Assoc for Left {
+    const RIGHT: bool = false;
+}
+
+impl Assoc for Right {
+    const RIGHT: bool = true;
+}
+
+/// The argument an operator table is parsed with, carrying the position and the minimum binding
+/// power an operator must have to be accepted.
+pub struct OperatorArg<'a, T, A> {
+    arg: &'a A,
+    prefix: bool,
+    min_bp: u64,
+    _t: PhantomData<fn() -> T>,
+}
+
+pub enum Operator<T, A> {
+    Prefix(u64, fn((T,), &A) -> T),
+    Infix(u64, fn((T, T), &A) -> T),
+    Postfix(fn((T,), &A) -> T),
+}
+
+/// A prefix operator matched by `Op`, applying `M` to its operand.
+pub struct Prefix<Op, M, const BP: u32>(PhantomData<(Op, M)>);
+
+/// An infix operator matched by `Op`, applying `M` to its operands.
+pub struct Infix<Op, M, const BP: u32, As = Left>(PhantomData<(Op, M, As)>);
+
+/// A postfix operator matched by `Op`, applying `M` to its operand.
+pub struct Postfix<Op, M, const BP: u32>(PhantomData<(Op, M)>);
+
+/// Parses operands with `Atom` and operators with the table `Ops` by precedence climbing, where
+/// operators with a higher binding power bind tighter.
+pub struct Expr<Atom, Ops>(PhantomData<(Atom, Ops)>);
+
+impl<'a, I, T, A, Op: ParseImpl<I, A>, M: Mapper<(T,), A, Output = T>, const BP: u32>
+    ParseImpl<I, OperatorArg<'a, T, A>> for Prefix<Op, M, BP>
+{
+    type Output = (Operator<T, A>,);
+    impl_parse!(parse, _await, |input: I, arg: OperatorArg<'a, T, A>| {
+        if !arg.prefix {
+            return Err(Error::Mismatch);
+        }
+        parse!(Op, input, arg.arg)?;
+        Ok((Operator::Prefix(BP as u64 * 2 + 1, M::map),))
+    });
+}
+
+impl<
+        'a,
+        I,
+        T,
+        A,
+        Op: ParseImpl<I, A>,
+        M: Mapper<(T, T), A, Output = T>,
+        As: Assoc,
+        const BP: u32,
+    > ParseImpl<I, OperatorArg<'a, T, A>> for Infix<Op, M, BP, As>
+{
+    type Output = (Operator<T, A>,);
+    impl_parse!(parse, _await, |input: I, arg: OperatorArg<'a, T, A>| {
+        let (l, r) = if As::RIGHT {
+            (BP as u64 * 2 + 1, BP as u64 * 2)
+        } else {
+            (BP as u64 * 2, BP as u64 * 2 + 1)
+        };
+        if arg.prefix || l < arg.min_bp {
+            return Err(Error::Mismatch);
+        }
+        parse!(Op, input, arg.arg)?;
+        Ok((Operator::Infix(r, M::map),))
+    });
+}
+
+impl<'a, I, T, A, Op: ParseImpl<I, A>, M: Mapper<(T,), A, Output = T>, const BP: u32>
+    ParseImpl<I, OperatorArg<'a, T, A>> for Postfix<Op, M, BP>
+{
+    type Output = (Operator<T, A>,);
+    impl_parse!(parse, _await, |input: I, arg: OperatorArg<'a, T, A>| {
+        if arg.prefix || (BP as u64 * 2 + 1) < arg.min_bp {
+            return Err(Error::Mismatch);
+        }
+        parse!(Op, input, arg.arg)?;
+        Ok((Operator::Postfix(M::map),))
+    });
+}
+
+enum Frame<T, A> {
+    Prefix(fn((T,), &A) -> T),
+    Infix(T, fn((T, T), &A) -> T),
+}
+
+impl<I, T, A, Atom: ParseImpl<I, A, Output = (T,)>, Ops> ParseImpl<I, A> for Expr<Atom, Ops>
+where
+    Ops: for<'a> ParseImpl<I, OperatorArg<'a, T, A>, Output = (Operator<T, A>,)>,
+{
+    type Output = (T,);
+    impl_parse!(parse, _await, |input: I, arg: A| {
+        let mut stack = Vec::new();
+        let mut min_bp = 0;
+
+        macro_rules! operator {
+            ($prefix:expr) => {{
+                let op_arg = OperatorArg {
+                    arg,
+                    prefix: $prefix,
+                    min_bp,
+                    _t: PhantomData,
+                };
+                let mut fork = input.fork();
+                match parse!(Ops, &mut fork, &op_arg) {
+                    Ok((op,)) => {
+                        fork.join();
+                        Some(op)
+                    }
+                    Err(e @ Error::Hard(_)) => {
+                        fork.join();
+                        return Err(e);
+                    }
+                    Err(_) => None,
+                }
+            }};
+        }
+
+        'operand: loop {
+            while let Some(Operator::Prefix(bp, f)) = operator!(true) {
+                stack.push((min_bp, Frame::Prefix(f)));
+                min_bp = bp;
+            }
+            let (mut lhs,) = parse!(Atom, input, arg)?;
+            loop {
+                match operator!(false) {
+                    Some(Operator::Postfix(f)) => lhs = f((lhs,), arg),
+                    Some(Operator::Infix(bp, f)) => {
+                        stack.push((min_bp, Frame::Infix(lhs, f)));
+                        min_bp = bp;
+                        continue 'operand;
+                    }
+                    _ => match stack.pop() {
+                        Some((bp, Frame::Prefix(f))) => {
+                            lhs = f((lhs,), arg);
+                            min_bp = bp;
+                        }
+                        Some((bp, Frame::Infix(l, f))) => {
+                            lhs = f((l, lhs), arg);
+                            min_bp = bp;
+                        }
+                        None => return Ok((lhs,)),
+                    },
+                }
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Expr, Infix, Postfix, Prefix, Right};
+    use crate::{
+        ops::{num::Dec, And, Discard, Or, A},
+        parser,
+        source::from_slice,
+        Parse, Source,
+    };
+
+    #[parser]
+    type Atom = Or<Dec<i64>, And<Discard<A<b'('>>, Calc, Discard<A<b')'>>>>;
+
+    #[parser]
+    type Ops = Or<
+        Infix<
+            A<b'+'>,
+            {
+                fn map(l: i64, r: i64) -> i64 {
+                    l + r
+                }
+            },
+            1,
+        >,
+        Infix<
+            A<b'-'>,
+            {
+                fn map(l: i64, r: i64) -> i64 {
+                    l - r
+                }
+            },
+            1,
+        >,
+        Infix<
+            A<b'*'>,
+            {
+                fn map(l: i64, r: i64) -> i64 {
+                    l * r
+                }
+            },
+            2,
+        >,
+        Infix<
+            A<b'^'>,
+            {
+                fn map(l: i64, r: i64) -> i64 {
+                    l.pow(r as u32)
+                }
+            },
+            3,
+            Right,
+        >,
+        Prefix<
+            A<b'-'>,
+            {
+                fn map(v: i64) -> i64 {
+                    -v
+                }
+            },
+            2,
+        >,
+        Postfix<
+            A<b'!'>,
+            {
+                fn map(v: i64) -> i64 {
+                    (1..=v).product()
+                }
+            },
+            4,
+        >,
+    >;
+
+    #[parser(u8, i64)]
+    type Calc = Expr<Atom, Ops>;
+
+    #[test]
+    fn expr_test() {
+        for (text, value) in [
+            ("1+2*3", 7),
+            ("10-4-3", 3),
+            ("2^3^2", 512),
+            ("-2^2", -4),
+            ("-(1+2)*3!", -18),
+            ("2*-3!+1", -11),
+            ("(((4)))", 4),
+        ] {
+            assert_eq!(
+                Calc::parse(&mut from_slice(text.as_bytes())).unwrap(),
+                value
+            );
+        }
+
+        let mut source = from_slice(b"1+2)");
+        assert_eq!(Calc::parse(&mut source).unwrap(), 3);
+        assert_eq!(source.read(2).unwrap(), b")");
+        assert!(Calc::parse(&mut from_slice(b"*1")).is_err());
+    }
+}
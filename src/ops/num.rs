@@ -0,0 +1,365 @@
+use super::Char;
+#[cfg(feature = "async")]
+use crate::AsyncSource;
+use crate::{
+    parse::{macros::impl_parse, ParseImpl},
+    Error, HardError, Result, Source, SourceBase,
+};
+use alloc::string::String;
+use core::{marker::PhantomData, str::FromStr};
+
+pub trait Integer: Copy {
+    const SIGNED: bool;
+    const ZERO: Self;
+    fn push_digit(self, digit: u32, radix: u32, negative: bool) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    ($signed:literal: $($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const SIGNED: bool = $signed;
+                const ZERO: Self = 0;
+                #[inline(always)]
+                fn push_digit(self, digit: u32, radix: u32, negative: bool) -> Option<Self> {
+                    let v = self.checked_mul(radix as Self)?;
+                    if negative {
+                        v.checked_sub(digit as Self)
+                    } else {
+                        v.checked_add(digit as Self)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(false: u8, u16, u32, u64, u128, usize);
+impl_integer!(true: i8, i16, i32, i64, i128, isize);
+
+pub trait Floating: FromStr {
+    fn is_infinite(&self) -> bool;
+}
+
+macro_rules! impl_floating {
+    ($($t:ty),*) => {
+        $(
+            impl Floating for $t {
+                #[inline(always)]
+                fn is_infinite(&self) -> bool {
+                    <$t>::is_infinite(*self)
+                }
+            }
+        )*
+    };
+}
+
+impl_floating!(f32, f64);
+
+/// Matches an integer of the given radix, with an optional sign for signed types and optional
+/// `_` separators after the first digit if `SEP` is set. `RADIX` must be within `2..=36`.
+pub struct Int<T, const RADIX: u32 = 10, const SEP: bool = false>(PhantomData<T>);
+
+pub type Dec<T, const SEP: bool = false> = Int<T, 10, SEP>;
+pub type Hex<T, const SEP: bool = false> = Int<T, 16, SEP>;
+pub type Oct<T, const SEP: bool = false> = Int<T, 8, SEP>;
+pub type Bin<T, const SEP: bool = false> = Int<T, 2, SEP>;
+
+/// Like [`Int`], but detects the radix from a `0x`, `0o` or `0b` prefix, defaulting to decimal.
+pub struct Prefixed<T, const SEP: bool = false>(PhantomData<T>);
+
+/// Matches a decimal floating point number with an optional sign, fraction and exponent, failing
+/// with an overflow if it is out of range. If `STRICT` is set, only the JSON number grammar is
+/// accepted: no `+` sign, no leading zeros, and digits on both sides of a point.
+pub struct Float<T, const STRICT: bool = false>(PhantomData<T>);
+
+struct Sign;
+
+struct Digits<T, const SEP: bool>(PhantomData<T>);
+
+impl<I: Char, A> ParseImpl<I, A> for Sign {
+    type Output = (bool,);
+    impl_parse!(parse, _await, |input: I, _arg: A| {
+        match _await!(input.read(1))?
+            .first()
+            .copied()
+            .and_then(Char::to_ascii)
+        {
+            Some(b'-') => {
+                input.consume(1);
+                Ok((true,))
+            }
+            Some(b'+') => {
+                input.consume(1);
+                Ok((false,))
+            }
+            _ => Ok((false,)),
+        }
+    });
+}
+
+impl<I: Char, T: Integer, const SEP: bool> ParseImpl<I, (u32, bool)> for Digits<T, SEP> {
+    type Output = (T,);
+    impl_parse!(parse, _await, |input: I, arg: (u32, bool)| {
+        let &(radix, negative) = arg;
+        let position = input.position();
+        let mut value = T::ZERO;
+        let mut len = 0;
+        // Separators are only consumed when followed by a digit.
+        let mut end = 0;
+        loop {
+            let items = _await!(input.read(len + 1))?;
+            let Some(c) = items.get(len).copied().and_then(Char::to_ascii) else {
+                break;
+            };
+            if SEP && end != 0 && c == b'_' {
+                len += 1;
+                continue;
+            }
+            let Some(digit) = (c as char).to_digit(radix) else {
+                break;
+            };
+            value = value
+                .push_digit(digit, radix, negative)
+                .ok_or(HardError::Overflow { position })?;
+            len += 1;
+            end = len;
+        }
+        if end == 0 {
+            return Err(Error::Mismatch);
+        }
+        input.consume(end);
+        Ok((value,))
+    });
+}
+
+impl<I: Char, T: Integer, A, const RADIX: u32, const SEP: bool> ParseImpl<I, A>
+    for Int<T, RADIX, SEP>
+{
+    type Output = (T,);
+    impl_parse!(parse, _await, |input: I, arg: A| {
+        const { assert!(2 <= RADIX && RADIX <= 36) };
+        let mut input = input.fork();
+        let (negative,) = if T::SIGNED {
+            parse!(Sign, &mut input, arg)?
+        } else {
+            (false,)
+        };
+        let result = parse!(Digits<T, SEP>, &mut input, &(RADIX, negative))?;
+        input.join();
+        Ok(result)
+    });
+}
+
+impl<I: Char, T: Integer, A, const SEP: bool> ParseImpl<I, A> for Prefixed<T, SEP> {
+    type Output = (T,);
+    impl_parse!(parse, _await, |input: I, arg: A| {
+        let mut input = input.fork();
+        let (negative,) = if T::SIGNED {
+            parse!(Sign, &mut input, arg)?
+        } else {
+            (false,)
+        };
+        let items = _await!(input.read(2))?;
+        let prefix = (
+            items.first().copied().and_then(Char::to_ascii),
+            items.get(1).copied().and_then(Char::to_ascii),
+        );
+        let radix = match prefix {
+            (Some(b'0'), Some(b'x' | b'X')) => 16,
+            (Some(b'0'), Some(b'o' | b'O')) => 8,
+            (Some(b'0'), Some(b'b' | b'B')) => 2,
+            _ => 10,
+        };
+        if radix != 10 {
+            input.consume(2);
+        }
+        let result = parse!(Digits<T, SEP>, &mut input, &(radix, negative))?;
+        input.join();
+        Ok(result)
+    });
+}
+
+impl<I: Char, T: Floating, A, const STRICT: bool> ParseImpl<I, A> for Float<T, STRICT> {
+    type Output = (T,);
+    impl_parse!(parse, _await, |input: I, _arg: A| {
+        let mut input = input.fork();
+        let mut text = String::new();
+        let mut len = 0;
+
+        macro_rules! peek {
+            () => {
+                _await!(input.read(len + 1))?
+                    .get(len)
+                    .copied()
+                    .and_then(Char::to_ascii)
+            };
+        }
+
+        macro_rules! push {
+            ($c:expr) => {{
+                text.push($c as char);
+                len += 1;
+            }};
+        }
+
+        macro_rules! digits {
+            () => {{
+                let mut count = 0;
+                while let Some(c @ b'0'..=b'9') = peek!() {
+                    push!(c);
+                    count += 1;
+                }
+                count
+            }};
+        }
+
+        match peek!() {
+            Some(c @ b'-') => push!(c),
+            Some(c @ b'+') if !STRICT => push!(c),
+            _ => {}
+        }
+        let mut mantissa = if STRICT && peek!() == Some(b'0') {
+            push!(b'0');
+            1
+        } else {
+            digits!()
+        };
+        if STRICT && mantissa == 0 {
+            return Err(Error::Mismatch);
+        }
+        if let Some(b'.') = peek!() {
+            let mark = len;
+            push!(b'.');
+            let fraction = digits!();
+            if STRICT && fraction == 0 {
+                text.truncate(mark);
+                len = mark;
+            }
+            mantissa += fraction;
+        }
+        if mantissa == 0 {
+            return Err(Error::Mismatch);
+        }
+
+        if let Some(c @ (b'e' | b'E')) = peek!() {
+            let mark = len;
+            push!(c);
+            if let Some(c @ (b'-' | b'+')) = peek!() {
+                push!(c);
+            }
+            if digits!() == 0 {
+                text.truncate(mark);
+                len = mark;
+            }
+        }
+
+        let value = text.parse::<T>().map_err(|_| Error::Mismatch)?;
+        if value.is_infinite() {
+            return Err(HardError::Overflow {
+                position: input.position(),
+            }
+            .into());
+        }
+        input.consume(len);
+        input.join();
+        Ok((value,))
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Bin, Dec, Float, Hex, Int, Prefixed};
+    use crate::{source::from_slice, Error, HardError, Parse, Source, SourceBase};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn int_test() {
+        assert_eq!(Dec::<u32>::parse(&mut from_slice(b"1234x")).unwrap(), 1234);
+        assert_eq!(Dec::<i8>::parse(&mut from_slice(b"-128")).unwrap(), -128);
+        assert_eq!(Dec::<i8>::parse(&mut from_slice(b"+12")).unwrap(), 12);
+        assert_eq!(
+            Hex::<u16, true>::parse(&mut from_slice(b"ff_FF")).unwrap(),
+            0xffff
+        );
+        let mut source = from_slice(b"1_0__");
+        assert_eq!(Dec::<u8, true>::parse(&mut source).unwrap(), 10);
+        assert_eq!(source.read(3).unwrap(), b"__");
+        assert_eq!(Bin::<u8>::parse(&mut from_slice(b"1012")).unwrap(), 5);
+        assert_eq!(
+            Int::<u64, 36>::parse(&mut from_slice(b"zz")).unwrap(),
+            36 * 36 - 1
+        );
+        assert!(matches!(
+            Dec::<u8>::parse(&mut from_slice(b"-1")),
+            Err(Error::Mismatch)
+        ));
+        assert!(matches!(
+            Dec::<u8>::parse(&mut from_slice(b"_1")),
+            Err(Error::Mismatch)
+        ));
+
+        let mut source = from_slice(b" 256");
+        source.consume(1);
+        assert!(matches!(
+            Dec::<u8>::parse(&mut source),
+            Err(Error::Hard(HardError::Overflow { position: 1 }))
+        ));
+
+        let chars = "-0x7f 0b11 0o17 19 ".chars().collect::<Vec<_>>();
+        let mut source = from_slice(&chars);
+        let mut values = Vec::new();
+        while let Ok(v) = Prefixed::<i32>::parse(&mut source) {
+            values.push(v);
+            source.consume(1);
+        }
+        assert_eq!(values, [-0x7f, 3, 0o17, 19]);
+    }
+
+    #[test]
+    fn float_test() {
+        for (text, value, rest) in [
+            ("1", 1.0, ""),
+            ("-1.5e3,", -1500.0, ","),
+            ("2.e-1", 0.2, ""),
+            (".25", 0.25, ""),
+            ("3e", 3.0, "e"),
+            ("4E+x", 4.0, "E+x"),
+        ] {
+            let mut source = from_slice(text.as_bytes());
+            assert_eq!(Float::<f64>::parse(&mut source).unwrap(), value);
+            assert_eq!(source.read(4).unwrap(), rest.as_bytes());
+        }
+        assert!(Float::<f32>::parse(&mut from_slice(b"-.e1")).is_err());
+        assert!(matches!(
+            Float::<f64>::parse(&mut from_slice(b"1e999")),
+            Err(Error::Hard(HardError::Overflow { position: 0 }))
+        ));
+        assert!(matches!(
+            Float::<f32>::parse(&mut from_slice(b"-1e39")),
+            Err(Error::Hard(HardError::Overflow { position: 0 }))
+        ));
+        assert_eq!(
+            Float::<f64>::parse(&mut from_slice(b"1e-999")).unwrap(),
+            0.0
+        );
+
+        for (text, value, rest) in [
+            ("-0.5e2,", -50.0, ","),
+            ("12E-1", 1.2, ""),
+            ("01", 0.0, "1"),
+            ("1.", 1.0, "."),
+            ("2.e1", 2.0, ".e"),
+        ] {
+            let mut source = from_slice(text.as_bytes());
+            assert_eq!(Float::<f64, true>::parse(&mut source).unwrap(), value);
+            assert_eq!(source.read(2).unwrap(), rest.as_bytes());
+        }
+        for text in ["+1", ".5", "-", "-.5"] {
+            assert!(Float::<f64, true>::parse(&mut from_slice(text.as_bytes())).is_err());
+        }
+
+        let chars = "6.5".chars().collect::<Vec<_>>();
+        assert_eq!(Float::<f32>::parse(&mut from_slice(&chars)).unwrap(), 6.5);
+    }
+}
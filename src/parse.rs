@@ -16,6 +16,9 @@ pub trait Parse<I> {
 
 pub trait ParseImpl<I, A> {
     type Output;
+    const CUT: bool = false;
+    /// Set by parsers that never match, such as the padding arms of alternations.
+    const NEVER: bool = false;
     fn parse<S: Source<Item = I>>(input: &mut S, arg: &A) -> Result<Self::Output>;
 
     #[cfg(feature = "async")]
@@ -10,13 +10,17 @@ use {
     futures_core::Stream,
 };
 
-use crate::Result;
+use crate::{Recovered, Result, Warning};
+use alloc::{boxed::Box, collections::BTreeMap, vec::Vec};
+use core::any::{Any, TypeId};
 
 #[cfg(not(feature = "async"))]
 pub fn from_slice<T>(slice: &[T]) -> impl Source<Item = T> + '_ {
     OwnedSource {
         position: 0,
         r#impl: slice,
+        reports: Reports::default(),
+        memo: MemoTable::default(),
     }
 }
 
@@ -25,6 +29,8 @@ pub fn from_slice<T>(slice: &[T]) -> impl Source<Item = T> + AsyncSource<Item =
     OwnedSource {
         position: 0,
         r#impl: slice,
+        reports: Reports::default(),
+        memo: MemoTable::default(),
     }
 }
 
@@ -32,6 +38,8 @@ pub fn from_slice<T>(slice: &[T]) -> impl Source<Item = T> + AsyncSource<Item =
 pub fn form_stream<S: Stream + Unpin>(stream: S) -> impl AsyncSource<Item = S::Item> {
     OwnedSource {
         position: 0,
+        reports: Reports::default(),
+        memo: MemoTable::default(),
         r#impl: BufferedStream {
             buffer: VecDeque::new(),
             stream: AsResult(stream),
@@ -58,6 +66,8 @@ pub fn form_try_stream<S: Stream<Item = Result<I, E>> + Unpin, I, E: Into<Error>
 ) -> impl AsyncSource<Item = I> {
     OwnedSource {
         position: 0,
+        reports: Reports::default(),
+        memo: MemoTable::default(),
         r#impl: BufferedStream {
             buffer: VecDeque::new(),
             stream,
@@ -69,23 +79,159 @@ pub trait SourceBase {
     type Item;
     fn consume(&mut self, len: usize);
     fn position(&self) -> usize;
+    /// The number of items that can be read without waiting for more input.
+    #[inline(always)]
+    fn available(&self) -> usize {
+        0
+    }
+    /// The position this source cannot read past, which is `usize::MAX` unless it is bounded.
+    #[inline(always)]
+    fn end(&self) -> usize {
+        usize::MAX
+    }
     fn join(self);
+    /// Records an error recovered from, which sources that do not collect reports discard.
+    #[inline(always)]
+    fn report(&mut self, _recovered: Recovered) {}
+    #[inline(always)]
+    fn take_recovered(&mut self) -> Vec<Recovered> {
+        Vec::new()
+    }
+    /// Records a warning, which sources that do not collect reports discard.
+    #[inline(always)]
+    fn warn(&mut self, _warning: Warning) {}
+    #[inline(always)]
+    fn take_warnings(&mut self) -> Vec<Warning> {
+        Vec::new()
+    }
+    #[doc(hidden)]
+    fn memo(&mut self) -> &mut MemoTable;
 }
 
 pub trait Source: SourceBase {
     fn fork(&mut self) -> impl Source<Item = Self::Item>;
+    /// Forks a source that cannot read more than `len` items past the current position.
+    fn fork_bounded(&mut self, len: usize) -> impl Source<Item = Self::Item>;
     fn read(&mut self, len: usize) -> Result<&[Self::Item]>;
 }
 
 #[cfg(feature = "async")]
 pub trait AsyncSource: SourceBase {
     fn fork(&mut self) -> impl AsyncSource<Item = Self::Item>;
+    fn fork_bounded(&mut self, len: usize) -> impl AsyncSource<Item = Self::Item>;
     fn read(&mut self, len: usize) -> impl Future<Output = Result<&[Self::Item]>>;
 }
 
 struct OwnedSource<T> {
     r#impl: T,
     position: usize,
+    reports: Reports,
+    memo: MemoTable,
+}
+
+/// Results of parsing rules at given positions and window ends, shared by a source and all of its
+/// forks. Entries below the position of the source are dropped as it consumes past them.
+#[derive(Default)]
+pub struct MemoTable(Option<Box<MemoEntries>>);
+
+type MemoEntries = BTreeMap<usize, BTreeMap<(usize, TypeId), Box<dyn Any>>>;
+
+impl MemoTable {
+    #[inline]
+    pub(crate) fn get_mut<T: Any>(
+        &mut self,
+        key: TypeId,
+        position: usize,
+        end: usize,
+    ) -> Option<&mut T> {
+        self.0
+            .as_mut()?
+            .get_mut(&position)?
+            .get_mut(&(end, key))?
+            .downcast_mut()
+    }
+
+    #[inline]
+    pub(crate) fn insert<T: Any>(&mut self, key: TypeId, position: usize, end: usize, value: T) {
+        self.0
+            .get_or_insert_with(Default::default)
+            .entry(position)
+            .or_default()
+            .insert((end, key), Box::new(value));
+    }
+
+    #[inline]
+    pub(crate) fn remove<T: Any>(&mut self, key: TypeId, position: usize, end: usize) -> Option<T> {
+        let entries = self.0.as_mut()?;
+        let at = entries.get_mut(&position)?;
+        let value = at.remove(&(end, key))?;
+        if at.is_empty() {
+            entries.remove(&position);
+        }
+        value.downcast().ok().map(|x| *x)
+    }
+
+    #[inline]
+    fn evict(&mut self, position: usize) {
+        if let Some(entries) = &mut self.0 {
+            if entries
+                .first_key_value()
+                .is_some_and(|(&x, _)| x < position)
+            {
+                **entries = entries.split_off(&position);
+            }
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn len(&self) -> usize {
+        self.0
+            .as_ref()
+            .map_or(0, |x| x.values().map(BTreeMap::len).sum())
+    }
+}
+
+#[derive(Default)]
+struct Reports(Option<Box<ReportLists>>);
+
+#[derive(Default)]
+struct ReportLists {
+    recovered: Vec<Recovered>,
+    warnings: Vec<Warning>,
+}
+
+impl Reports {
+    #[inline]
+    fn lists(&mut self) -> &mut ReportLists {
+        self.0.get_or_insert_with(Default::default)
+    }
+
+    #[inline]
+    fn take_recovered(&mut self) -> Vec<Recovered> {
+        self.0
+            .as_mut()
+            .map_or_else(Vec::new, |x| core::mem::take(&mut x.recovered))
+    }
+
+    #[inline]
+    fn take_warnings(&mut self) -> Vec<Warning> {
+        self.0
+            .as_mut()
+            .map_or_else(Vec::new, |x| core::mem::take(&mut x.warnings))
+    }
+
+    #[inline]
+    fn append(&mut self, other: Self) {
+        let Some(mut other) = other.0 else {
+            return;
+        };
+        if let Some(lists) = &mut self.0 {
+            lists.recovered.append(&mut other.recovered);
+            lists.warnings.append(&mut other.warnings);
+        } else {
+            self.0 = Some(other);
+        }
+    }
 }
 
 impl<T: SourceImplBase> SourceBase for OwnedSource<T> {
@@ -96,6 +242,7 @@ impl<T: SourceImplBase> SourceBase for OwnedSource<T> {
         debug_assert!(len <= self.r#impl.available());
         self.position += len;
         self.r#impl.consume(len);
+        self.memo.evict(self.position);
     }
 
     #[inline(always)]
@@ -103,8 +250,38 @@ impl<T: SourceImplBase> SourceBase for OwnedSource<T> {
         self.position
     }
 
+    #[inline(always)]
+    fn available(&self) -> usize {
+        self.r#impl.available()
+    }
+
     #[inline(always)]
     fn join(self) {}
+
+    #[inline(always)]
+    fn report(&mut self, recovered: Recovered) {
+        self.reports.lists().recovered.push(recovered);
+    }
+
+    #[inline(always)]
+    fn take_recovered(&mut self) -> Vec<Recovered> {
+        self.reports.take_recovered()
+    }
+
+    #[inline(always)]
+    fn warn(&mut self, warning: Warning) {
+        self.reports.lists().warnings.push(warning);
+    }
+
+    #[inline(always)]
+    fn take_warnings(&mut self) -> Vec<Warning> {
+        self.reports.take_warnings()
+    }
+
+    #[inline(always)]
+    fn memo(&mut self) -> &mut MemoTable {
+        &mut self.memo
+    }
 }
 
 impl<T: SourceImpl> Source for OwnedSource<T> {
@@ -114,6 +291,19 @@ impl<T: SourceImpl> Source for OwnedSource<T> {
             target: self,
             parent: None,
             offset: 0,
+            end: usize::MAX,
+            reports: Reports::default(),
+        }
+    }
+
+    #[inline(always)]
+    fn fork_bounded(&mut self, len: usize) -> impl Source<Item = Self::Item> {
+        SourceRef {
+            end: self.position.saturating_add(len),
+            target: self,
+            parent: None,
+            offset: 0,
+            reports: Reports::default(),
         }
     }
 
@@ -131,6 +321,19 @@ impl<T: AsyncSourceImpl> AsyncSource for OwnedSource<T> {
             target: self,
             parent: None,
             offset: 0,
+            end: usize::MAX,
+            reports: Reports::default(),
+        }
+    }
+
+    #[inline(always)]
+    fn fork_bounded(&mut self, len: usize) -> impl AsyncSource<Item = Self::Item> {
+        SourceRef {
+            end: self.position.saturating_add(len),
+            target: self,
+            parent: None,
+            offset: 0,
+            reports: Reports::default(),
         }
     }
 
@@ -142,8 +345,17 @@ impl<T: AsyncSourceImpl> AsyncSource for OwnedSource<T> {
 
 struct SourceRef<'a, T> {
     target: &'a mut OwnedSource<T>,
-    parent: Option<&'a mut usize>,
+    parent: Option<(&'a mut usize, &'a mut Reports)>,
     offset: usize,
+    end: usize,
+    reports: Reports,
+}
+
+impl<T> SourceRef<'_, T> {
+    #[inline(always)]
+    fn remaining(&self) -> usize {
+        self.end.saturating_sub(self.target.position + self.offset)
+    }
 }
 
 impl<T: SourceImplBase> SourceBase for SourceRef<'_, T> {
@@ -152,6 +364,7 @@ impl<T: SourceImplBase> SourceBase for SourceRef<'_, T> {
     #[inline(always)]
     fn consume(&mut self, len: usize) {
         debug_assert!(self.offset + len <= self.target.r#impl.available());
+        debug_assert!(len <= self.remaining());
         self.offset += len;
     }
 
@@ -160,28 +373,73 @@ impl<T: SourceImplBase> SourceBase for SourceRef<'_, T> {
         self.target.position + self.offset
     }
 
+    #[inline(always)]
+    fn available(&self) -> usize {
+        (self.target.r#impl.available() - self.offset).min(self.remaining())
+    }
+
+    #[inline(always)]
+    fn end(&self) -> usize {
+        self.end
+    }
+
     #[inline(always)]
     fn join(self) {
-        if let Some(parent) = self.parent {
-            *parent = self.offset
+        if let Some((parent, parent_reports)) = self.parent {
+            *parent = self.offset;
+            parent_reports.append(self.reports);
         } else {
             self.target.consume(self.offset);
+            self.target.reports.append(self.reports);
         }
     }
+
+    #[inline(always)]
+    fn report(&mut self, recovered: Recovered) {
+        self.reports.lists().recovered.push(recovered);
+    }
+
+    #[inline(always)]
+    fn take_recovered(&mut self) -> Vec<Recovered> {
+        self.reports.take_recovered()
+    }
+
+    #[inline(always)]
+    fn warn(&mut self, warning: Warning) {
+        self.reports.lists().warnings.push(warning);
+    }
+
+    #[inline(always)]
+    fn take_warnings(&mut self) -> Vec<Warning> {
+        self.reports.take_warnings()
+    }
+
+    #[inline(always)]
+    fn memo(&mut self) -> &mut MemoTable {
+        &mut self.target.memo
+    }
 }
 
 impl<T: SourceImpl> Source for SourceRef<'_, T> {
     #[inline(always)]
     fn fork(&mut self) -> impl Source<Item = Self::Item> {
+        Source::fork_bounded(self, usize::MAX)
+    }
+
+    #[inline(always)]
+    fn fork_bounded(&mut self, len: usize) -> impl Source<Item = Self::Item> {
         SourceRef {
+            end: self.end.min(self.position().saturating_add(len)),
             target: self.target,
             offset: self.offset,
-            parent: Some(&mut self.offset),
+            parent: Some((&mut self.offset, &mut self.reports)),
+            reports: Reports::default(),
         }
     }
 
     #[inline(always)]
     fn read(&mut self, len: usize) -> Result<&[Self::Item]> {
+        let len = len.min(self.remaining());
         Ok(&self.target.r#impl.read(self.offset + len)?[self.offset..])
     }
 }
@@ -190,15 +448,23 @@ impl<T: SourceImpl> Source for SourceRef<'_, T> {
 impl<T: AsyncSourceImpl> AsyncSource for SourceRef<'_, T> {
     #[inline(always)]
     fn fork(&mut self) -> impl AsyncSource<Item = Self::Item> {
+        AsyncSource::fork_bounded(self, usize::MAX)
+    }
+
+    #[inline(always)]
+    fn fork_bounded(&mut self, len: usize) -> impl AsyncSource<Item = Self::Item> {
         SourceRef {
+            end: self.end.min(self.position().saturating_add(len)),
             target: self.target,
             offset: self.offset,
-            parent: Some(&mut self.offset),
+            parent: Some((&mut self.offset, &mut self.reports)),
+            reports: Reports::default(),
         }
     }
 
     #[inline(always)]
     async fn read(&mut self, len: usize) -> Result<&[Self::Item]> {
+        let len = len.min(self.remaining());
         Ok(&self.target.r#impl.read(self.offset + len).await?[self.offset..])
     }
 }